@@ -0,0 +1,352 @@
+use crate::aggregation::{
+    check_weights, granularity, saturated_delta, symbolic_values, AggregationError,
+};
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::LabelMembership;
+use crate::valuation::TwoTuple;
+
+/// 2-tuple Bonferroni mean,
+/// `BM^(p,q) = (Σ x_i^p · x_j^q / (n · (n - 1)))^(1 / (p + q))` for every `i != j`.
+///
+/// Values `x_i` are symbolic values normalized by domain granularity, Δ<sup>-1</sup>(x<sub>i</sub>) / g.
+/// A single valuation is its own mean.
+///
+/// # Arguments
+/// * `valuations`: Valuations over the same domain.
+/// * `p`: Non negative exponent.
+/// * `q`: Non negative exponent, with `p + q > 0`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::bonferroni_mean;
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(),
+/// ];
+///
+/// assert_eq!(bonferroni_mean(&valuations, 1.0, 1.0).unwrap().to_string(), "(c, -0.08)");
+/// assert_eq!(bonferroni_mean(&valuations[..1], 1.0, 1.0).unwrap().to_string(), "(b, 0.20)");
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidMeanParameters**: If `p` or `q` are negative or non finite, or
+/// `p + q == 0`.
+///
+/// ```
+/// # use assessment::aggregation::{bonferroni_mean, AggregationError};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap(),
+/// ];
+///
+/// assert_eq!(
+///     bonferroni_mean(&valuations, -1.0, 2.0),
+///     Err(AggregationError::InvalidMeanParameters { p: -1.0, q: 2.0 })
+/// );
+/// ```
+pub fn bonferroni_mean<'domain, T, H>(
+    valuations: &[TwoTuple<'domain, T, H>],
+    p: f32,
+    q: f32,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    let weight = 1.0 / valuations.len() as f64;
+    weighted_pairs(
+        valuations.iter().map(|valuation| (valuation, weight)),
+        p,
+        q,
+        |i, j, weights| i != j && weights[i] < 1.0,
+        |i, j, weights| weights[i] * weights[j] / (1.0 - weights[i]),
+    )
+}
+
+/// 2-tuple weighted Bonferroni mean,
+/// `WBM^(p,q) = (Σ w_i · w_j / (1 - w_i) · x_i^p · x_j^q)^(1 / (p + q))` for every `i != j`.
+///
+/// See [bonferroni_mean]. Equal weights lead to the Bonferroni mean and a weight equal to 1 to
+/// the corresponding valuation.
+///
+/// # Arguments
+/// * `valuations`: Pairs of valuation and weight, with valuations over the same domain and
+///   non negative weights summing up to 1.
+/// * `p`: Non negative exponent.
+/// * `q`: Non negative exponent, with `p + q > 0`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::weighted_bonferroni_mean;
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     (TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(), 0.5),
+///     (TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(), 0.3),
+///     (TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(), 0.2),
+/// ];
+///
+/// assert_eq!(
+///     weighted_bonferroni_mean(&valuations, 1.0, 1.0).unwrap().to_string(),
+///     "(c, -0.18)"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// ```
+/// # use assessment::aggregation::{weighted_bonferroni_mean, AggregationError};
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::valuation::TwoTuple;
+/// let valuations: [(TwoTuple<Trapezoidal>, f32); 0] = [];
+///
+/// assert_eq!(weighted_bonferroni_mean(&valuations, 1.0, 1.0), Err(AggregationError::Empty));
+/// ```
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidWeight**: If a weight is negative or non finite.
+///
+/// **AggregationError::InvalidWeightsSum**: If weights don't sum up to 1.
+///
+/// **AggregationError::InvalidMeanParameters**: If `p` or `q` are negative or non finite, or
+/// `p + q == 0`.
+pub fn weighted_bonferroni_mean<'domain, T, H>(
+    valuations: &[(TwoTuple<'domain, T, H>, f32)],
+    p: f32,
+    q: f32,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    if valuations.is_empty() {
+        return Err(AggregationError::Empty);
+    }
+    check_weights(valuations.iter().map(|(_, weight)| *weight))?;
+    weighted_pairs(
+        valuations
+            .iter()
+            .map(|(valuation, weight)| (valuation, *weight as f64)),
+        p,
+        q,
+        |i, j, weights| i != j && weights[i] < 1.0,
+        |i, j, weights| weights[i] * weights[j] / (1.0 - weights[i]),
+    )
+}
+
+/// 2-tuple Heronian mean,
+/// `HM^(p,q) = (2 · Σ x_i^p · x_j^q / (n · (n + 1)))^(1 / (p + q))` for every `i <= j`.
+///
+/// Values `x_i` are symbolic values normalized by domain granularity, Δ<sup>-1</sup>(x<sub>i</sub>) / g.
+///
+/// # Arguments
+/// * `valuations`: Valuations over the same domain.
+/// * `p`: Non negative exponent.
+/// * `q`: Non negative exponent, with `p + q > 0`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::heronian_mean;
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(),
+/// ];
+///
+/// assert_eq!(heronian_mean(&valuations, 1.0, 1.0).unwrap().to_string(), "(c, -0.01)");
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidMeanParameters**: If `p` or `q` are negative or non finite, or
+/// `p + q == 0`.
+///
+/// ```
+/// # use assessment::aggregation::{heronian_mean, AggregationError};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap(),
+/// ];
+///
+/// assert_eq!(
+///     heronian_mean(&valuations, 0.0, 0.0),
+///     Err(AggregationError::InvalidMeanParameters { p: 0.0, q: 0.0 })
+/// );
+/// ```
+pub fn heronian_mean<'domain, T, H>(
+    valuations: &[TwoTuple<'domain, T, H>],
+    p: f32,
+    q: f32,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    let weight = 1.0 / valuations.len() as f64;
+    weighted_heronian_pairs(valuations.iter().map(|valuation| (valuation, weight)), p, q)
+}
+
+/// 2-tuple weighted Heronian mean,
+/// `WHM^(p,q) = (Σ w_i · w_j · x_i^p · x_j^q / Σ w_i · w_j)^(1 / (p + q))` for every `i <= j`.
+///
+/// See [heronian_mean]. Equal weights lead to the Heronian mean.
+///
+/// # Arguments
+/// * `valuations`: Pairs of valuation and weight, with valuations over the same domain and
+///   non negative weights summing up to 1.
+/// * `p`: Non negative exponent.
+/// * `q`: Non negative exponent, with `p + q > 0`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::weighted_heronian_mean;
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     (TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(), 0.5),
+///     (TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(), 0.3),
+///     (TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(), 0.2),
+/// ];
+///
+/// assert_eq!(
+///     weighted_heronian_mean(&valuations, 1.0, 1.0).unwrap().to_string(),
+///     "(c, -0.22)"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// ```
+/// # use assessment::aggregation::{weighted_heronian_mean, AggregationError};
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::valuation::TwoTuple;
+/// let valuations: [(TwoTuple<Trapezoidal>, f32); 0] = [];
+///
+/// assert_eq!(weighted_heronian_mean(&valuations, 1.0, 1.0), Err(AggregationError::Empty));
+/// ```
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidWeight**: If a weight is negative or non finite.
+///
+/// **AggregationError::InvalidWeightsSum**: If weights don't sum up to 1.
+///
+/// **AggregationError::InvalidMeanParameters**: If `p` or `q` are negative or non finite, or
+/// `p + q == 0`.
+pub fn weighted_heronian_mean<'domain, T, H>(
+    valuations: &[(TwoTuple<'domain, T, H>, f32)],
+    p: f32,
+    q: f32,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    if valuations.is_empty() {
+        return Err(AggregationError::Empty);
+    }
+    check_weights(valuations.iter().map(|(_, weight)| *weight))?;
+    weighted_heronian_pairs(
+        valuations
+            .iter()
+            .map(|(valuation, weight)| (valuation, *weight as f64)),
+        p,
+        q,
+    )
+}
+
+/// Heronian means given valuations and weights (see [weighted_heronian_mean]).
+fn weighted_heronian_pairs<'a, 'domain: 'a, T, H>(
+    valuations: impl Iterator<Item = (&'a TwoTuple<'domain, T, H>, f64)>,
+    p: f32,
+    q: f32,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq + 'a,
+    H: DomainHandle<Qualitative<T>> + 'a,
+{
+    let valuations: Vec<_> = valuations.collect();
+    let mut total = 0.0;
+    for i in 0..valuations.len() {
+        for j in i..valuations.len() {
+            total += valuations[i].1 * valuations[j].1;
+        }
+    }
+    weighted_pairs(
+        valuations.into_iter(),
+        p,
+        q,
+        |i, j, _| i <= j,
+        |i, j, weights| weights[i] * weights[j] / total,
+    )
+}
+
+/// `(Σ coefficient(i, j) · x_i^p · x_j^q)^(1 / (p + q))` for every pair `(i, j)` accepted by
+/// `pair`, given valuations and weights.
+fn weighted_pairs<'a, 'domain: 'a, T, H>(
+    valuations: impl Iterator<Item = (&'a TwoTuple<'domain, T, H>, f64)>,
+    p: f32,
+    q: f32,
+    pair: impl Fn(usize, usize, &[f64]) -> bool,
+    coefficient: impl Fn(usize, usize, &[f64]) -> f64,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq + 'a,
+    H: DomainHandle<Qualitative<T>> + 'a,
+{
+    if !(p.is_finite() && q.is_finite() && p >= 0.0 && q >= 0.0 && p + q > 0.0) {
+        return Err(AggregationError::InvalidMeanParameters { p, q });
+    }
+    let (valuations, weights): (Vec<_>, Vec<_>) = valuations.unzip();
+    let (first, values) = symbolic_values(valuations)?;
+    let g = granularity(first);
+    let values: Vec<f64> = values.iter().map(|value| value / g).collect();
+
+    // A single valuation, or a weight equal to 1, leaves no pair to combine
+    if let Some(single) = weights.iter().position(|weight| *weight >= 1.0) {
+        return Ok(saturated_delta(first, values[single] * g));
+    }
+    let (p, q) = (p as f64, q as f64);
+    let mut sum = 0.0;
+    for i in 0..values.len() {
+        for j in 0..values.len() {
+            if pair(i, j, &weights) {
+                sum += coefficient(i, j, &weights) * values[i].powf(p) * values[j].powf(q);
+            }
+        }
+    }
+    Ok(saturated_delta(first, sum.powf(1.0 / (p + q)) * g))
+}
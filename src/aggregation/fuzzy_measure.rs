@@ -0,0 +1,708 @@
+use crate::aggregation::{saturated_delta, symbolic_values, AggregationError};
+use crate::domain::quantitative::NORMALIZATION_DOMAIN;
+use crate::domain::{DomainHandle, Qualitative, Quantitative};
+use crate::fuzzy::LabelMembership;
+use crate::utilities;
+use crate::valuation::{Numeric, TwoTuple};
+
+/// Maximum number of criteria of a fuzzy measure, as it stores the measure of every subset of
+/// them (`2^24` values, 64 MiB).
+pub const MAX_CRITERIA: usize = 24;
+
+/// Fuzzy measure (capacity) over a set of `n` criteria.
+///
+/// Subsets of criteria are given as lists of criterion indexes. Internally, the measure of each
+/// subset is stored at the position given by its bitmask.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FuzzyMeasure {
+    criteria: usize,
+    values: Vec<f32>,
+}
+
+impl FuzzyMeasure {
+    /// Creates a new fuzzy measure given the measure of every subset of criteria.
+    ///
+    /// # Arguments
+    /// * `criteria`: Number of criteria.
+    /// * `values`: Measure of each subset, indexed by its bitmask (the subset with criteria `0`
+    ///   and `2` is at position `0b101`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(measure.value(&[1]).unwrap(), 0.5);
+    /// assert_eq!(measure.value(&[0, 1]).unwrap(), 1.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::TooManyCriteria**: If `criteria > MAX_CRITERIA`.
+    ///
+    /// ```
+    /// # use assessment::aggregation::fuzzy_measure::MAX_CRITERIA;
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert_eq!(
+    ///     FuzzyMeasure::new(MAX_CRITERIA + 1, vec![0.0, 1.0]),
+    ///     Err(AggregationError::TooManyCriteria { criteria: MAX_CRITERIA + 1 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't `2^criteria` values.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert_eq!(
+    ///     FuzzyMeasure::new(2, vec![0.0, 0.3, 1.0]),
+    ///     Err(AggregationError::LengthMismatch { expected: 4, found: 3 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::InvalidMeasureValue**: If a measure is non finite or outside the
+    /// range `[0, 1]`.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert!(matches!(
+    ///     FuzzyMeasure::new(2, vec![0.0, f32::NAN, 0.5, 1.0]),
+    ///     Err(AggregationError::InvalidMeasureValue { subset, .. }) if subset == vec![0]
+    /// ));
+    /// assert_eq!(
+    ///     FuzzyMeasure::new(2, vec![0.0, 1.5, 0.5, 1.0]),
+    ///     Err(AggregationError::InvalidMeasureValue { subset: vec![0], measure: 1.5 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::InvalidMeasureBoundaries**: If the measure of the empty set isn't 0
+    /// or the measure of all criteria isn't 1.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert_eq!(
+    ///     FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 0.9]),
+    ///     Err(AggregationError::InvalidMeasureBoundaries { empty: 0.0, full: 0.9 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::NonMonotonicMeasure**: If a subset has a greater measure than one of
+    /// its supersets.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert_eq!(
+    ///     FuzzyMeasure::new(3, vec![0.0, 0.3, 0.5, 0.6, 0.4, 0.2, 0.7, 1.0]),
+    ///     Err(AggregationError::NonMonotonicMeasure { subset: vec![0], superset: vec![0, 2] })
+    /// );
+    /// ```
+    pub fn new(criteria: usize, values: Vec<f32>) -> Result<Self, AggregationError> {
+        let expected = subsets(criteria)?;
+        if values.len() != expected {
+            return Err(AggregationError::LengthMismatch {
+                expected,
+                found: values.len(),
+            });
+        }
+        // Same tolerance as the measure of all criteria, which may come from sums
+        if let Some(subset) = values
+            .iter()
+            .position(|measure| !(-1e-4..=1.0 + 1e-4).contains(measure))
+        {
+            return Err(AggregationError::InvalidMeasureValue {
+                subset: members(subset),
+                measure: values[subset],
+            });
+        }
+        let (empty, full) = (values[0], values[expected - 1]);
+        if empty != 0.0 || !utilities::math::approx_equal_f32(full, 1.0, 4) {
+            return Err(AggregationError::InvalidMeasureBoundaries { empty, full });
+        }
+        for subset in 0..expected {
+            for criterion in 0..criteria {
+                let superset = subset | (1 << criterion);
+                // Tolerance absorbs rounding errors of measures obtained from sums
+                if values[superset] < values[subset] - 1e-6 {
+                    return Err(AggregationError::NonMonotonicMeasure {
+                        subset: members(subset),
+                        superset: members(superset),
+                    });
+                }
+            }
+        }
+        Ok(Self { criteria, values })
+    }
+
+    /// λ-fuzzy (Sugeno) measure given the measure of each single criterion.
+    ///
+    /// The measure satisfies `μ(A ∪ B) = μ(A) + μ(B) + λ · μ(A) · μ(B)` for disjoint `A` and `B`
+    /// (see [FuzzyMeasure::sugeno_lambda]).
+    ///
+    /// # Arguments
+    /// * `densities`: Measure of each single criterion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::utilities;
+    /// let measure = FuzzyMeasure::sugeno(&[0.3, 0.4]).unwrap();
+    /// assert_eq!(measure.value(&[0]).unwrap(), 0.3);
+    /// assert_eq!(measure.value(&[1]).unwrap(), 0.4);
+    /// assert!(utilities::math::approx_equal_f32(measure.value(&[0, 1]).unwrap(), 1.0, 5));
+    ///
+    /// let measure = FuzzyMeasure::sugeno(&[0.2, 0.3, 0.5]).unwrap();
+    /// assert!(utilities::math::approx_equal_f32(measure.value(&[0, 2]).unwrap(), 0.7, 5));
+    /// assert_eq!(measure.value(&[0, 1, 2]).unwrap(), 1.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [FuzzyMeasure::sugeno_lambda] and [FuzzyMeasure::new].
+    pub fn sugeno(densities: &[f32]) -> Result<Self, AggregationError> {
+        let lambda = Self::sugeno_lambda(densities)?;
+        let mut values = vec![0.0; subsets(densities.len())?];
+        for subset in 1..values.len() {
+            let criterion = subset.trailing_zeros() as usize;
+            let (rest, density) = (values[subset & (subset - 1)], densities[criterion]);
+            values[subset] = rest + density + lambda * rest * density;
+        }
+        let full = values.len() - 1;
+        values[full] = 1.0;
+        Self::new(densities.len(), values)
+    }
+
+    /// Parameter λ of the λ-fuzzy measure given the measure of each single criterion, the
+    /// unique root greater than -1 of `1 + λ = Π (1 + λ · g_i)`.
+    ///
+    /// # Arguments
+    /// * `densities`: Measure of each single criterion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// for (densities, lambda) in [
+    ///     (vec![0.3, 0.4], 2.5),
+    ///     (vec![0.4, 0.6], 0.0),
+    ///     (vec![0.5, 1.0], -1.0),
+    ///     (vec![0.6, 0.8], -0.8333),
+    /// ] {
+    ///     let result = FuzzyMeasure::sugeno_lambda(&densities).unwrap();
+    ///     assert!((result - lambda).abs() < 0.0001, "{} vs {}", result, lambda);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If there are no densities.
+    ///
+    /// **AggregationError::InvalidDensity**: If a density is outside the range `[0, 1]`, all of
+    /// them are 0, or they sum up to less than 1 with fewer than two positive ones (there is no
+    /// such λ). In the last case, the first null density (if any) is reported.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert_eq!(
+    ///     FuzzyMeasure::sugeno_lambda(&[0.3, 1.2]),
+    ///     Err(AggregationError::InvalidDensity { index: 1, density: 1.2 })
+    /// );
+    /// assert_eq!(
+    ///     FuzzyMeasure::sugeno_lambda(&[0.0, 0.0]),
+    ///     Err(AggregationError::InvalidDensity { index: 0, density: 0.0 })
+    /// );
+    /// assert_eq!(
+    ///     FuzzyMeasure::sugeno(&[0.5, 0.0, 0.0]),
+    ///     Err(AggregationError::InvalidDensity { index: 1, density: 0.0 })
+    /// );
+    /// assert_eq!(
+    ///     FuzzyMeasure::sugeno_lambda(&[0.5]),
+    ///     Err(AggregationError::InvalidDensity { index: 0, density: 0.5 })
+    /// );
+    /// ```
+    pub fn sugeno_lambda(densities: &[f32]) -> Result<f32, AggregationError> {
+        if densities.is_empty() {
+            return Err(AggregationError::Empty);
+        }
+        for (index, density) in densities.iter().enumerate() {
+            if !(0.0..=1.0).contains(density) {
+                return Err(AggregationError::InvalidDensity {
+                    index,
+                    density: *density,
+                });
+            }
+        }
+        let sum: f32 = densities.iter().sum();
+        if sum == 0.0 {
+            return Err(AggregationError::InvalidDensity {
+                index: 0,
+                density: 0.0,
+            });
+        }
+        if utilities::math::approx_equal_f32(sum, 1.0, 6) {
+            return Ok(0.0);
+        }
+        if densities.contains(&1.0) {
+            return Ok(-1.0);
+        }
+        if sum < 1.0 && densities.iter().filter(|density| **density > 0.0).count() < 2 {
+            let index = densities
+                .iter()
+                .position(|density| *density == 0.0)
+                .unwrap_or(0);
+            return Err(AggregationError::InvalidDensity {
+                index,
+                density: densities[index],
+            });
+        }
+
+        // f(λ) = Π (1 + λ · g_i) - (1 + λ) is negative between 0 and the root
+        let f = |lambda: f64| {
+            densities
+                .iter()
+                .map(|density| 1.0 + lambda * *density as f64)
+                .product::<f64>()
+                - (1.0 + lambda)
+        };
+        let (mut low, mut high) = if sum < 1.0 {
+            let mut high: f64 = 1.0;
+            while f(high) < 0.0 {
+                high *= 2.0;
+                if !high.is_finite() {
+                    // Root beyond f64 range, only reachable with negligible densities
+                    let (index, density) = densities
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, density)| **density > 0.0)
+                        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                        .unwrap();
+                    return Err(AggregationError::InvalidDensity {
+                        index,
+                        density: *density,
+                    });
+                }
+            }
+            (0.0, high)
+        } else {
+            (-1.0, 0.0)
+        };
+        for _ in 0..100 {
+            let middle = (low + high) / 2.0;
+            if (f(middle) < 0.0) == (sum < 1.0) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(((low + high) / 2.0) as f32)
+    }
+
+    /// k-additive fuzzy measure given its Möbius representation.
+    ///
+    /// The measure of a subset `A` is `μ(A) = Σ m(B)` for every `B ⊆ A`, where `m(B)` is the
+    /// Möbius coefficient of `B`, which is 0 for subsets with more than `k` criteria or not given.
+    ///
+    /// # Arguments
+    /// * `criteria`: Number of criteria.
+    /// * `k`: Maximum number of criteria of subsets with non null Möbius coefficients.
+    /// * `mobius`: Pairs of subset and Möbius coefficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::utilities;
+    /// let measure = FuzzyMeasure::k_additive(3, 2, &[
+    ///     (vec![0], 0.2),
+    ///     (vec![1], 0.3),
+    ///     (vec![2], 0.3),
+    ///     (vec![0, 1], 0.3),
+    ///     (vec![1, 2], -0.1),
+    /// ]).unwrap();
+    ///
+    /// assert!(utilities::math::approx_equal_f32(measure.value(&[0, 1]).unwrap(), 0.8, 5));
+    /// assert!(utilities::math::approx_equal_f32(measure.value(&[1, 2]).unwrap(), 0.5, 5));
+    /// assert!((measure.mobius(&[1, 2]).unwrap() + 0.1).abs() < 0.00001);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::InvalidSubset**: If a subset has more than `k` criteria, repeated
+    /// criteria or criteria out of range.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// assert_eq!(
+    ///     FuzzyMeasure::k_additive(3, 1, &[(vec![0], 0.5), (vec![1, 2], 0.5)]),
+    ///     Err(AggregationError::InvalidSubset { subset: vec![1, 2] })
+    /// );
+    /// ```
+    ///
+    /// See also [FuzzyMeasure::new].
+    pub fn k_additive(
+        criteria: usize,
+        k: usize,
+        mobius: &[(Vec<usize>, f32)],
+    ) -> Result<Self, AggregationError> {
+        let mut coefficients = vec![0.0; subsets(criteria)?];
+        for (subset, coefficient) in mobius {
+            if subset.len() > k {
+                return Err(AggregationError::InvalidSubset {
+                    subset: subset.clone(),
+                });
+            }
+            coefficients[mask(criteria, subset)?] += coefficient;
+        }
+        let values = (0..coefficients.len())
+            .map(|subset| {
+                subsets_of(subset)
+                    .map(|inner| coefficients[inner])
+                    .sum::<f32>()
+            })
+            .collect();
+        Self::new(criteria, values)
+    }
+
+    /// Returns the number of criteria.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// assert_eq!(FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap().criteria(), 2);
+    /// ```
+    pub fn criteria(&self) -> usize {
+        self.criteria
+    }
+
+    /// Measure of a subset of criteria.
+    ///
+    /// # Arguments
+    /// * `subset`: Criterion indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(measure.value(&[]).unwrap(), 0.0);
+    /// assert_eq!(measure.value(&[0]).unwrap(), 0.3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::InvalidSubset**: If `subset` has repeated criteria or criteria out of
+    /// range.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(measure.value(&[2]), Err(AggregationError::InvalidSubset { subset: vec![2] }));
+    /// ```
+    pub fn value(&self, subset: &[usize]) -> Result<f32, AggregationError> {
+        Ok(self.values[mask(self.criteria, subset)?])
+    }
+
+    /// Möbius coefficient of a subset of criteria, `m(A) = Σ (-1)^|A \ B| · μ(B)` for every
+    /// `B ⊆ A`.
+    ///
+    /// # Arguments
+    /// * `subset`: Criterion indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::utilities;
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(measure.mobius(&[0]).unwrap(), 0.3);
+    /// assert!(utilities::math::approx_equal_f32(measure.mobius(&[0, 1]).unwrap(), 0.2, 5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::InvalidSubset**: If `subset` has repeated criteria or criteria out of
+    /// range.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(
+    ///     measure.mobius(&[0, 0]),
+    ///     Err(AggregationError::InvalidSubset { subset: vec![0, 0] })
+    /// );
+    /// ```
+    pub fn mobius(&self, subset: &[usize]) -> Result<f32, AggregationError> {
+        let mask = mask(self.criteria, subset)?;
+        Ok(subsets_of(mask)
+            .map(|inner| {
+                let sign = if (mask ^ inner).count_ones().is_multiple_of(2) {
+                    1.0
+                } else {
+                    -1.0
+                };
+                sign * self.values[inner]
+            })
+            .sum())
+    }
+
+    /// Shapley importance index of each criterion,
+    /// `φ_i = Σ (n - |A| - 1)! · |A|! / n! · (μ(A ∪ {i}) - μ(A))` for every `A ⊆ N \ {i}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::utilities;
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// for (shapley, expected) in measure.shapley().iter().zip([0.4, 0.6]) {
+    ///     assert!(utilities::math::approx_equal_f32(*shapley, expected, 5));
+    /// }
+    /// ```
+    pub fn shapley(&self) -> Vec<f32> {
+        let n = self.criteria;
+        (0..n)
+            .map(|i| {
+                let bit = 1 << i;
+                (0..self.values.len())
+                    .filter(|subset| subset & bit == 0)
+                    .map(|subset| {
+                        let size = subset.count_ones() as usize;
+                        coefficient(n - 1, size)
+                            * (self.values[subset | bit] - self.values[subset]) as f64
+                    })
+                    .sum::<f64>() as f32
+            })
+            .collect()
+    }
+
+    /// Shapley interaction index between criteria `i` and `j`,
+    /// `I_ij = Σ (n - |A| - 2)! · |A|! / (n - 1)! · (μ(A ∪ {i, j}) - μ(A ∪ {i}) - μ(A ∪ {j}) + μ(A))`
+    /// for every `A ⊆ N \ {i, j}`.
+    ///
+    /// Positive values mean complementary criteria and negative values redundant ones.
+    ///
+    /// # Arguments
+    /// * `i`: First criterion index.
+    /// * `j`: Second criterion index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::utilities;
+    /// let measure = FuzzyMeasure::k_additive(3, 2, &[
+    ///     (vec![0], 0.2),
+    ///     (vec![1], 0.3),
+    ///     (vec![2], 0.3),
+    ///     (vec![0, 1], 0.3),
+    ///     (vec![1, 2], -0.1),
+    /// ]).unwrap();
+    ///
+    /// for (i, j, expected) in [(0, 1, 0.3), (1, 2, -0.1), (0, 2, 0.0)] {
+    ///     assert!((measure.interaction(i, j).unwrap() - expected).abs() < 0.00001);
+    /// }
+    /// for (shapley, expected) in measure.shapley().iter().zip([0.35, 0.4, 0.25]) {
+    ///     assert!(utilities::math::approx_equal_f32(*shapley, expected, 5));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::InvalidSubset**: If `i == j` or any of them is out of range.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(
+    ///     measure.interaction(1, 1),
+    ///     Err(AggregationError::InvalidSubset { subset: vec![1, 1] })
+    /// );
+    /// ```
+    pub fn interaction(&self, i: usize, j: usize) -> Result<f32, AggregationError> {
+        mask(self.criteria, &[i, j])?;
+        let (bit_i, bit_j) = (1 << i, 1 << j);
+        Ok((0..self.values.len())
+            .filter(|subset| subset & (bit_i | bit_j) == 0)
+            .map(|subset| {
+                let size = subset.count_ones() as usize;
+                let difference = self.values[subset | bit_i | bit_j]
+                    - self.values[subset | bit_i]
+                    - self.values[subset | bit_j]
+                    + self.values[subset];
+                coefficient(self.criteria - 2, size) * difference as f64
+            })
+            .sum::<f64>() as f32)
+    }
+
+    /// Choquet integral of `values`, `Σ (x_(i) - x_(i-1)) · μ(A_(i))`, where `x_(i)` are values
+    /// sorted in ascending order and `A_(i)` the criteria with values `>= x_(i)`.
+    ///
+    /// # Arguments
+    /// * `values`: Value of each criterion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::utilities;
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert!(utilities::math::approx_equal_f64(measure.choquet(&[0.8, 0.4]).unwrap(), 0.52, 5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there isn't a value for each criterion.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, FuzzyMeasure};
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    /// assert_eq!(
+    ///     measure.choquet(&[0.8]),
+    ///     Err(AggregationError::LengthMismatch { expected: 2, found: 1 })
+    /// );
+    /// ```
+    pub fn choquet(&self, values: &[f64]) -> Result<f64, AggregationError> {
+        if values.len() != self.criteria {
+            return Err(AggregationError::LengthMismatch {
+                expected: self.criteria,
+                found: values.len(),
+            });
+        }
+        let mut order: Vec<usize> = (0..self.criteria).collect();
+        order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+        let mut subset = self.values.len() - 1;
+        let mut previous = 0.0;
+        let mut result = 0.0;
+        for criterion in order {
+            result += (values[criterion] - previous) * self.values[subset] as f64;
+            previous = values[criterion];
+            subset &= !(1 << criterion);
+        }
+        Ok(result)
+    }
+
+    /// Choquet integral of normalized numeric valuations (see [Numeric::normalize]).
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuation of each criterion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let valuations = [8.0, 4.0].map(|value| Numeric::new(&domain, value).unwrap());
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    ///
+    /// let result = measure.choquet_numeric(&valuations).unwrap();
+    /// assert!(utilities::math::approx_equal_f64(result.value(), 0.52, 5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there isn't a valuation for each criterion.
+    pub fn choquet_numeric<'domain, H>(
+        &self,
+        valuations: &[Numeric<'domain, f64, H>],
+    ) -> Result<Numeric<'static, f64>, AggregationError>
+    where
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        let values: Vec<f64> = valuations
+            .iter()
+            .map(|valuation| valuation.normalize().value())
+            .collect();
+        let value = self.choquet(&values)?;
+        Ok(Numeric::new(&NORMALIZATION_DOMAIN, value.clamp(0.0, 1.0)).unwrap())
+    }
+
+    /// Choquet integral of 2-tuple valuations through their symbolic values.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuation of each criterion, over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::FuzzyMeasure;
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [
+    ///     TwoTuple::new_by_label_index(&domain, 3, 0.0).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+    /// ];
+    /// let measure = FuzzyMeasure::new(2, vec![0.0, 0.3, 0.5, 1.0]).unwrap();
+    ///
+    /// assert_eq!(measure.choquet_two_tuple(&valuations).unwrap().to_string(), "(c, -0.26)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there isn't a valuation for each criterion.
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn choquet_two_tuple<'domain, T, H>(
+        &self,
+        valuations: &[TwoTuple<'domain, T, H>],
+    ) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+    where
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        let values: Vec<f64> = valuations
+            .iter()
+            .map(|valuation| valuation.inverse_delta() as f64)
+            .collect();
+        let beta = self.choquet(&values)?;
+        let (first, _) = symbolic_values(valuations)?;
+        Ok(saturated_delta(first, beta))
+    }
+}
+
+/// Bitmask of `subset`, a subset of `criteria` criteria without repeated ones.
+pub(crate) fn mask(criteria: usize, subset: &[usize]) -> Result<usize, AggregationError> {
+    subset
+        .iter()
+        .try_fold(0usize, |mask, criterion| {
+            let bit = 1usize.checked_shl(*criterion as u32)?;
+            (*criterion < criteria && mask & bit == 0).then_some(mask | bit)
+        })
+        .ok_or_else(|| AggregationError::InvalidSubset {
+            subset: subset.to_vec(),
+        })
+}
+
+/// Criterion indexes of a bitmask.
+pub(crate) fn members(mask: usize) -> Vec<usize> {
+    (0..usize::BITS as usize)
+        .filter(|criterion| mask & (1 << criterion) != 0)
+        .collect()
+}
+
+/// Number of subsets of `criteria` criteria, if there are at most [MAX_CRITERIA].
+pub(crate) fn subsets(criteria: usize) -> Result<usize, AggregationError> {
+    if criteria > MAX_CRITERIA {
+        return Err(AggregationError::TooManyCriteria { criteria });
+    }
+    Ok(1 << criteria)
+}
+
+/// Every subset of a bitmask, including the empty one and itself.
+fn subsets_of(mask: usize) -> impl Iterator<Item = usize> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = (current != 0).then(|| (current - 1) & mask);
+        Some(current)
+    })
+}
+
+/// `(n - s)! · s! / (n + 1)!`, the weight of subsets with `s` out of `n` remaining criteria.
+fn coefficient(n: usize, s: usize) -> f64 {
+    let factorial = |k: usize| (1..=k).map(|i| i as f64).product::<f64>();
+    factorial(n - s) * factorial(s) / factorial(n + 1)
+}
@@ -0,0 +1,351 @@
+use crate::aggregation::{check_weights, AggregationError};
+use crate::domain::Qualitative;
+use crate::fuzzy::membership::Trapezoidal;
+use crate::valuation::{TwoTuple, TwoTupleError, Unified, UnifiedError};
+use crate::Valuation;
+use std::fmt::{Display, Formatter};
+
+/// Aggregation of heterogeneous information (numeric, interval and linguistic valuations).
+///
+/// Valuations go through the following stages, whose values remain available once the pipeline
+/// has been run:
+///
+/// 1. **Unification**: Every valuation is expressed as a fuzzy set over a target BLTS domain
+///    (see [Valuation::unification]).
+/// 2. **Aggregation**: Fuzzy sets are aggregated label by label into a unified valuation.
+/// 3. **Transformation**: The aggregated fuzzy set is transformed into a 2-tuple valuation in
+///    the target domain through its `chi` value (see [Unified::chi]).
+#[derive(Debug, PartialEq)]
+pub struct HeterogeneousPipeline<'domain> {
+    unified: Vec<Unified<'domain>>,
+    aggregated: Unified<'domain>,
+    result: TwoTuple<'domain, Trapezoidal>,
+}
+
+/// Heterogeneous pipeline errors types.
+#[derive(Debug, PartialEq)]
+pub enum HeterogeneousError<'domain> {
+    /// Invalid values to aggregate or weights, or error returned by the aggregation operator.
+    Aggregation { error: AggregationError },
+    /// Non-BLTS target domain.
+    NonBLTSDomain {
+        domain: &'domain Qualitative<Trapezoidal>,
+    },
+    /// Valuation that can't be unified.
+    InvalidUnification {
+        index: usize,
+        error: UnifiedError<'domain>,
+    },
+    /// Aggregated measure outside the range [0, 1].
+    InvalidAggregatedMeasure { index: usize, measure: f32 },
+    /// Aggregated measures that don't build a unified valuation.
+    InvalidAggregatedValuation { error: UnifiedError<'domain> },
+    /// Aggregated unified valuation that can't be transformed into a 2-tuple valuation.
+    InvalidTransformation {
+        error: TwoTupleError<'domain, Trapezoidal>,
+    },
+}
+
+impl<'domain> Display for HeterogeneousError<'domain> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use HeterogeneousError::*;
+        match &self {
+            Aggregation { error } => write!(f, "{}", error),
+            NonBLTSDomain { domain } => write!(f, "Domain {} is not a BLTS domain.", domain),
+            InvalidUnification { index, error } => {
+                write!(
+                    f,
+                    "Valuation at position {} can't be unified. {}",
+                    index, error
+                )
+            }
+            InvalidAggregatedMeasure { index, measure } => {
+                write!(
+                    f,
+                    "Invalid aggregated measure {} for label at position {}. Value should be in range [0, 1].",
+                    measure, index
+                )
+            }
+            InvalidAggregatedValuation { error } => {
+                write!(
+                    f,
+                    "Aggregated measures aren't a unified valuation. {}",
+                    error
+                )
+            }
+            InvalidTransformation { error } => {
+                write!(f, "Aggregated valuation can't be transformed. {}", error)
+            }
+        }
+    }
+}
+
+impl<'domain> HeterogeneousPipeline<'domain> {
+    /// Runs the pipeline aggregating unified valuations through the weighted mean of their
+    /// measures, `γ[j] = Σ w[i] · γ<sub>i</sub>[j]`.
+    ///
+    /// # Arguments
+    /// * `domain`: Target BLTS domain.
+    /// * `valuations`: Valuations to aggregate, of any kind and over any domain.
+    /// * `weights`: Weight of each valuation, non negative and summing up to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::HeterogeneousPipeline;
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Interval, Numeric, Single, TwoTuple};
+    /// # use assessment::Valuation;
+    /// let numeric_domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let linguistic_domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// let valuations: Vec<Box<dyn Valuation>> = vec![
+    ///     Box::new(Numeric::new(&numeric_domain, 7.5).unwrap()),
+    ///     Box::new(Interval::new(&numeric_domain, 2.5, 5.0).unwrap()),
+    ///     Box::new(Single::new_by_label_name(&linguistic_domain, "b").unwrap()),
+    ///     Box::new(TwoTuple::new_by_label_name(&linguistic_domain, "c", -0.25).unwrap()),
+    /// ];
+    ///
+    /// let pipeline = HeterogeneousPipeline::new(&blts, &valuations, &[0.4, 0.2, 0.2, 0.2]).unwrap();
+    /// assert_eq!(
+    ///     pipeline.unified().iter().map(|unified| unified.to_string()).collect::<Vec<_>>(),
+    ///     vec![
+    ///         "[a => 0.00, b => 0.00, c => 0.00, d => 1.00, e => 0.00]",
+    ///         "[a => 0.00, b => 1.00, c => 1.00, d => 0.00, e => 0.00]",
+    ///         "[a => 0.00, b => 0.00, c => 1.00, d => 0.00, e => 0.00]",
+    ///         "[a => 0.00, b => 0.00, c => 0.00, d => 0.50, e => 0.50]",
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     pipeline.aggregated().to_string(),
+    ///     "[a => 0.00, b => 0.20, c => 0.40, d => 0.50, e => 0.10]"
+    /// );
+    /// assert_eq!(pipeline.result().to_string(), "(c, 0.42)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HeterogeneousError::Aggregation**: If there are no valuations
+    /// ([AggregationError::Empty]), there aren't as many weights as valuations
+    /// ([AggregationError::LengthMismatch]), a weight is negative or non finite
+    /// ([AggregationError::InvalidWeight]) or weights don't sum up to 1
+    /// ([AggregationError::InvalidWeightsSum]).
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, HeterogeneousError, HeterogeneousPipeline};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::Valuation;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let valuations: Vec<Box<dyn Valuation>> = vec![];
+    ///
+    /// assert_eq!(
+    ///     HeterogeneousPipeline::new(&domain, &valuations, &[]),
+    ///     Err(HeterogeneousError::Aggregation { error: AggregationError::Empty })
+    /// );
+    /// ```
+    ///
+    /// **HeterogeneousError::NonBLTSDomain**: If `domain` is a non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{HeterogeneousError, HeterogeneousPipeline};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::Valuation;
+    /// let domain = qualitative_symmetric_domain!["a", "b"].unwrap();
+    /// let valuations: Vec<Box<dyn Valuation>> =
+    ///     vec![Box::new(Single::new_by_label_name(&domain, "a").unwrap())];
+    ///
+    /// assert_eq!(
+    ///     HeterogeneousPipeline::new(&domain, &valuations, &[1.0]),
+    ///     Err(HeterogeneousError::NonBLTSDomain { domain: &domain })
+    /// );
+    /// ```
+    ///
+    /// **HeterogeneousError::InvalidUnification**: If a valuation can't be unified in `domain`,
+    /// with the unification error.
+    pub fn new(
+        domain: &'domain Qualitative<Trapezoidal>,
+        valuations: &[Box<dyn Valuation + '_>],
+        weights: &[f32],
+    ) -> Result<Self, HeterogeneousError<'domain>> {
+        let invalid_aggregation = |error| HeterogeneousError::Aggregation { error };
+        if valuations.is_empty() {
+            return Err(invalid_aggregation(AggregationError::Empty));
+        }
+        if weights.len() != valuations.len() {
+            return Err(invalid_aggregation(AggregationError::LengthMismatch {
+                expected: valuations.len(),
+                found: weights.len(),
+            }));
+        }
+        check_weights(weights.iter().copied()).map_err(invalid_aggregation)?;
+        Self::with_operator(domain, valuations, |measures| {
+            Ok(measures
+                .iter()
+                .zip(weights)
+                .map(|(measure, weight)| measure * *weight as f64)
+                .sum())
+        })
+    }
+
+    /// Runs the pipeline aggregating unified valuations label by label with a custom operator.
+    ///
+    /// # Arguments
+    /// * `domain`: Target BLTS domain.
+    /// * `valuations`: Valuations to aggregate, of any kind and over any domain.
+    /// * `operator`: Operator aggregating the measures of a label in every unified valuation
+    ///   (in the same order as `valuations`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{HeterogeneousPipeline, OwaWeights};
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Numeric, Single};
+    /// # use assessment::Valuation;
+    /// let numeric_domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let linguistic_domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// let valuations: Vec<Box<dyn Valuation>> = vec![
+    ///     Box::new(Numeric::new(&numeric_domain, 1.25).unwrap()),
+    ///     Box::new(Single::new_by_label_name(&linguistic_domain, "c").unwrap()),
+    /// ];
+    /// let weights = OwaWeights::new(vec![1.0, 0.0]).unwrap();
+    ///
+    /// let pipeline =
+    ///     HeterogeneousPipeline::with_operator(&blts, &valuations, |measures| weights.aggregate(measures))
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     pipeline.aggregated().to_string(),
+    ///     "[a => 0.50, b => 0.50, c => 0.00, d => 0.00, e => 1.00]"
+    /// );
+    /// assert_eq!(pipeline.result().to_string(), "(c, 0.25)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HeterogeneousError::Aggregation**: If there are no valuations
+    /// ([AggregationError::Empty]), or with the errors returned by `operator`.
+    ///
+    /// **HeterogeneousError::NonBLTSDomain**: If `domain` is a non-BLTS domain.
+    ///
+    /// **HeterogeneousError::InvalidUnification**: If a valuation can't be unified in `domain`,
+    /// with the unification error.
+    ///
+    /// **HeterogeneousError::InvalidAggregatedMeasure**: If an aggregated measure is outside the
+    /// range `[0, 1]`.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{HeterogeneousError, HeterogeneousPipeline};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::Valuation;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let valuations: Vec<Box<dyn Valuation>> =
+    ///     vec![Box::new(Single::new_by_label_name(&domain, "a").unwrap())];
+    ///
+    /// assert_eq!(
+    ///     HeterogeneousPipeline::with_operator(&domain, &valuations, |measures| Ok(measures[0] * 2.0)),
+    ///     Err(HeterogeneousError::InvalidAggregatedMeasure { index: 0, measure: 2.0 })
+    /// );
+    /// ```
+    ///
+    /// **HeterogeneousError::InvalidAggregatedValuation**: If aggregated measures don't build a
+    /// unified valuation, with the unification error.
+    ///
+    /// **HeterogeneousError::InvalidTransformation**: If the aggregated valuation can't be
+    /// transformed into a 2-tuple valuation, with the transformation error.
+    pub fn with_operator(
+        domain: &'domain Qualitative<Trapezoidal>,
+        valuations: &[Box<dyn Valuation + '_>],
+        operator: impl Fn(&[f64]) -> Result<f64, AggregationError>,
+    ) -> Result<Self, HeterogeneousError<'domain>> {
+        if valuations.is_empty() {
+            return Err(HeterogeneousError::Aggregation {
+                error: AggregationError::Empty,
+            });
+        }
+        if !domain.is_blts() {
+            return Err(HeterogeneousError::NonBLTSDomain { domain });
+        }
+        let unified = valuations
+            .iter()
+            .enumerate()
+            .map(|(index, valuation)| {
+                valuation
+                    .unification(domain)
+                    .map_err(|error| HeterogeneousError::InvalidUnification { index, error })
+            })
+            .collect::<Result<Vec<Unified>, _>>()?;
+
+        let mut measures = Vec::with_capacity(domain.cardinality());
+        for index in 0..domain.cardinality() {
+            let label_measures: Vec<f64> = unified
+                .iter()
+                .map(|valuation| valuation.measures()[index] as f64)
+                .collect();
+            let measure = operator(&label_measures)
+                .map_err(|error| HeterogeneousError::Aggregation { error })?
+                as f32;
+            if !(0.0..=1.0).contains(&measure) {
+                return Err(HeterogeneousError::InvalidAggregatedMeasure { index, measure });
+            }
+            measures.push(measure);
+        }
+        let aggregated = Unified::new(domain, measures)
+            .map_err(|error| HeterogeneousError::InvalidAggregatedValuation { error })?;
+        let result = TwoTuple::try_from(&aggregated)
+            .map_err(|error| HeterogeneousError::InvalidTransformation { error })?;
+        Ok(Self {
+            unified,
+            aggregated,
+            result,
+        })
+    }
+
+    /// Returns target domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::HeterogeneousPipeline;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::Valuation;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations: Vec<Box<dyn Valuation>> =
+    ///     vec![Box::new(Single::new_by_label_name(&domain, "b").unwrap())];
+    ///
+    /// let pipeline = HeterogeneousPipeline::new(&blts, &valuations, &[1.0]).unwrap();
+    /// assert_eq!(*pipeline.domain(), blts);
+    /// ```
+    pub fn domain(&self) -> &'domain Qualitative<Trapezoidal> {
+        self.aggregated.domain()
+    }
+
+    /// Returns unified valuations, in the same order as the original ones (first stage).
+    ///
+    /// See [HeterogeneousPipeline::new].
+    pub fn unified(&self) -> &[Unified<'domain>] {
+        &self.unified
+    }
+
+    /// Returns aggregated unified valuation (second stage).
+    ///
+    /// See [HeterogeneousPipeline::new].
+    pub fn aggregated(&self) -> &Unified<'domain> {
+        &self.aggregated
+    }
+
+    /// Returns resulting 2-tuple valuation (third stage).
+    ///
+    /// See [HeterogeneousPipeline::new].
+    pub fn result(&self) -> &TwoTuple<'domain, Trapezoidal> {
+        &self.result
+    }
+}
@@ -0,0 +1,375 @@
+use crate::aggregation::{rescale_index, AggregationError, OwaWeights};
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::LabelMembership;
+use crate::valuation::{Linguistic, Single, TwoTuple};
+
+/// Pair of assessment and importance.
+type WeightedAssessment<'domain, 'importance, T, H, U, G> =
+    (Single<'domain, T, H>, Single<'importance, U, G>);
+
+/// Operators combining an importance degree `c` and an assessment `a`, both labels of the same
+/// domain with maximum label `s_g` and negation `Neg` (see [Single::neg]).
+///
+/// Following Herrera and Herrera-Viedma, conjunctions (LC<sup>→</sup>) are suited for
+/// or-like aggregations (orness >= 0.5) and implications (LI<sup>→</sup>) for and-like ones.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ImportanceOperator {
+    /// Classical conjunction, `min(c, a)`.
+    Min,
+    /// Nilpotent conjunction, `min(c, a)` if `c > Neg(a)`, `s_0` otherwise.
+    NilpotentMin,
+    /// Weakest conjunction, `min(c, a)` if `max(c, a) = s_g`, `s_0` otherwise.
+    WeakestConjunction,
+    /// Kleene-Dienes implication, `max(Neg(c), a)`.
+    KleeneDienes,
+    /// Gödel implication, `s_g` if `c <= a`, `a` otherwise.
+    Godel,
+    /// Fodor (nilpotent) implication, `s_g` if `c <= a`, `max(Neg(c), a)` otherwise.
+    Fodor,
+}
+
+impl ImportanceOperator {
+    /// Combines `importance` and `assessment`, defined over the same domain.
+    ///
+    /// # Arguments
+    /// * `importance`: Importance label.
+    /// * `assessment`: Assessment label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::ImportanceOperator;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let label = |index| Single::new_by_label_index(&domain, index).unwrap();
+    ///
+    /// for (operator, expected) in [
+    ///     (ImportanceOperator::Min, [3, 1, 0]),
+    ///     (ImportanceOperator::NilpotentMin, [3, 0, 0]),
+    ///     (ImportanceOperator::WeakestConjunction, [3, 0, 0]),
+    ///     (ImportanceOperator::KleeneDienes, [3, 2, 4]),
+    ///     (ImportanceOperator::Godel, [3, 1, 4]),
+    ///     (ImportanceOperator::Fodor, [3, 2, 4]),
+    /// ] {
+    ///     for ((importance, assessment), expected) in [(4, 3), (2, 1), (0, 4)].into_iter().zip(expected) {
+    ///         let result = operator.apply(&label(importance), &label(assessment)).unwrap();
+    ///         assert_eq!(result, label(expected));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::DomainMismatch**: If `importance` and `assessment` are defined over
+    /// different domains.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, ImportanceOperator};
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["low", "high"].unwrap();
+    /// let importance = Single::new_by_label_index(&other, 1).unwrap();
+    /// let assessment = Single::new_by_label_index(&domain, 2).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ImportanceOperator::Min.apply(&importance, &assessment),
+    ///     Err(AggregationError::DomainMismatch { index: 1 })
+    /// );
+    /// ```
+    pub fn apply<'domain, T, H>(
+        &self,
+        importance: &Single<'domain, T, H>,
+        assessment: &Single<'domain, T, H>,
+    ) -> Result<Single<'domain, T, H>, AggregationError>
+    where
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        use ImportanceOperator::*;
+        if importance.check_same_domain(assessment).is_err() {
+            return Err(AggregationError::DomainMismatch { index: 1 });
+        }
+        let (c, a) = (importance.index(), assessment.index());
+        let g = assessment.cardinality() - 1;
+        let index = match self {
+            Min => c.min(a),
+            NilpotentMin if c > assessment.neg().index() => c.min(a),
+            WeakestConjunction if c.max(a) == g => c.min(a),
+            NilpotentMin | WeakestConjunction => 0,
+            KleeneDienes => importance.neg().index().max(a),
+            Godel | Fodor if c <= a => g,
+            Godel => a,
+            Fodor => importance.neg().index().max(a),
+        };
+        Ok(assessment.with_index(index))
+    }
+}
+
+/// Linguistic weighted disjunction (LWD), `max(min(c_i, a_i))`.
+///
+/// Importance labels may belong to a different domain than assessments. In such case, they are
+/// mapped to the nearest assessment label by their relative position.
+///
+/// # Arguments
+/// * `valuations`: Pairs of assessment and importance, with assessments over the same domain
+///   and importances over the same domain.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::lwd;
+/// # use assessment::valuation::Single;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+/// let valuations = [("d", "high"), ("b", "medium"), ("e", "low")].map(|(a, c)| {
+///     (
+///         Single::new_by_label_name(&domain, a).unwrap(),
+///         Single::new_by_label_name(&importance, c).unwrap(),
+///     )
+/// });
+///
+/// assert_eq!(lwd(&valuations).unwrap().to_string(), "d");
+/// ```
+///
+/// Assessments and importances may also own their domains:
+///
+/// ```
+/// # use assessment::aggregation::lwd;
+/// # use assessment::valuation::Single;
+/// # use assessment::qualitative_symmetric_domain;
+/// # use std::sync::Arc;
+/// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap());
+/// let importance = Arc::new(qualitative_symmetric_domain!["low", "medium", "high"].unwrap());
+/// let valuations = [("d", "high"), ("b", "medium"), ("e", "low")].map(|(a, c)| {
+///     (
+///         Single::new_shared_by_label_name(&domain, a).unwrap(),
+///         Single::new_shared_by_label_name(&importance, c).unwrap(),
+///     )
+/// });
+///
+/// let handle = std::thread::spawn(move || lwd(&valuations).unwrap().to_string());
+/// assert_eq!(handle.join().unwrap(), "d");
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// **AggregationError::DomainMismatch**: If assessments or importances are defined over
+/// different domains.
+///
+/// ```
+/// # use assessment::aggregation::{lwd, AggregationError};
+/// # use assessment::valuation::Single;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+/// let other = qualitative_symmetric_domain!["low", "high"].unwrap();
+/// let valuations = [
+///     (Single::new_by_label_index(&domain, 1).unwrap(), Single::new_by_label_index(&importance, 2).unwrap()),
+///     (Single::new_by_label_index(&domain, 2).unwrap(), Single::new_by_label_index(&other, 1).unwrap()),
+/// ];
+///
+/// assert_eq!(lwd(&valuations), Err(AggregationError::DomainMismatch { index: 1 }));
+/// ```
+pub fn lwd<'domain, T, H, U, G>(
+    valuations: &[WeightedAssessment<'domain, '_, T, H, U, G>],
+) -> Result<Single<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+    U: LabelMembership + PartialEq,
+    G: DomainHandle<Qualitative<U>>,
+{
+    let (first, indexes) = transformed(valuations, ImportanceOperator::Min)?;
+    Ok(first.with_index(indexes.into_iter().max().unwrap()))
+}
+
+/// Linguistic weighted conjunction (LWC), `min(max(Neg(c_i), a_i))`.
+///
+/// See [lwd] for importance domains.
+///
+/// # Arguments
+/// * `valuations`: Pairs of assessment and importance, with assessments over the same domain
+///   and importances over the same domain.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::lwc;
+/// # use assessment::valuation::Single;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+/// let valuations = [("d", "high"), ("b", "medium"), ("e", "low")].map(|(a, c)| {
+///     (
+///         Single::new_by_label_name(&domain, a).unwrap(),
+///         Single::new_by_label_name(&importance, c).unwrap(),
+///     )
+/// });
+///
+/// assert_eq!(lwc(&valuations).unwrap().to_string(), "c");
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// **AggregationError::DomainMismatch**: If assessments or importances are defined over
+/// different domains.
+pub fn lwc<'domain, T, H, U, G>(
+    valuations: &[WeightedAssessment<'domain, '_, T, H, U, G>],
+) -> Result<Single<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+    U: LabelMembership + PartialEq,
+    G: DomainHandle<Qualitative<U>>,
+{
+    let (first, indexes) = transformed(valuations, ImportanceOperator::KleeneDienes)?;
+    Ok(first.with_index(indexes.into_iter().min().unwrap()))
+}
+
+/// Linguistic weighted averaging (LWA), LOWA aggregation of `operator(c_i, a_i)`.
+///
+/// See [lwd] for importance domains and [OwaWeights::aggregate_single] for LOWA.
+///
+/// # Arguments
+/// * `valuations`: Pairs of assessment and importance, with assessments over the same domain
+///   and importances over the same domain, as many as weights.
+/// * `weights`: OWA weights.
+/// * `operator`: Operator combining importances and assessments.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::{lwa, ImportanceOperator, OwaWeights};
+/// # use assessment::valuation::Single;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+/// let valuations = [("d", "high"), ("b", "medium"), ("e", "low")].map(|(a, c)| {
+///     (
+///         Single::new_by_label_name(&domain, a).unwrap(),
+///         Single::new_by_label_name(&importance, c).unwrap(),
+///     )
+/// });
+/// let weights = OwaWeights::new(vec![0.5, 0.3, 0.2]).unwrap();
+///
+/// for (operator, expected) in [
+///     (ImportanceOperator::Min, "c"),
+///     (ImportanceOperator::KleeneDienes, "e"),
+/// ] {
+///     assert_eq!(lwa(&valuations, &weights, operator).unwrap().to_string(), expected);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::LengthMismatch**: If there aren't as many valuations as weights.
+///
+/// **AggregationError::DomainMismatch**: If assessments or importances are defined over
+/// different domains.
+pub fn lwa<'domain, T, H, U, G>(
+    valuations: &[WeightedAssessment<'domain, '_, T, H, U, G>],
+    weights: &OwaWeights,
+    operator: ImportanceOperator,
+) -> Result<Single<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+    U: LabelMembership + PartialEq,
+    G: DomainHandle<Qualitative<U>>,
+{
+    let (first, indexes) = transformed(valuations, operator)?;
+    Ok(first.with_index(weights.convex_combination(indexes)?))
+}
+
+/// Linguistic weighted averaging with 2-tuple OWA aggregation of `operator(c_i, a_i)`.
+///
+/// See [lwa]. The result keeps the symbolic translation instead of rounding to a label.
+///
+/// # Arguments
+/// * `valuations`: Pairs of assessment and importance, with assessments over the same domain
+///   and importances over the same domain, as many as weights.
+/// * `weights`: OWA weights.
+/// * `operator`: Operator combining importances and assessments.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::{lwa_two_tuple, ImportanceOperator, OwaWeights};
+/// # use assessment::valuation::Single;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+/// let valuations = [("d", "high"), ("b", "medium"), ("e", "low")].map(|(a, c)| {
+///     (
+///         Single::new_by_label_name(&domain, a).unwrap(),
+///         Single::new_by_label_name(&importance, c).unwrap(),
+///     )
+/// });
+/// let weights = OwaWeights::new(vec![0.5, 0.3, 0.2]).unwrap();
+///
+/// for (operator, expected) in [
+///     (ImportanceOperator::Min, "(c, -0.20)"),
+///     (ImportanceOperator::KleeneDienes, "(d, 0.30)"),
+/// ] {
+///     assert_eq!(lwa_two_tuple(&valuations, &weights, operator).unwrap().to_string(), expected);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::LengthMismatch**: If there aren't as many valuations as weights.
+///
+/// **AggregationError::DomainMismatch**: If assessments or importances are defined over
+/// different domains.
+pub fn lwa_two_tuple<'domain, T, H, U, G>(
+    valuations: &[WeightedAssessment<'domain, '_, T, H, U, G>],
+    weights: &OwaWeights,
+    operator: ImportanceOperator,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+    U: LabelMembership + PartialEq,
+    G: DomainHandle<Qualitative<U>>,
+{
+    let (first, indexes) = transformed(valuations, operator)?;
+    let values: Vec<f64> = indexes.into_iter().map(|index| index as f64).collect();
+    let max = (first.cardinality() - 1) as f32;
+    let beta = weights.aggregate(&values)? as f32;
+    Ok(first.delta_in_domain(beta.clamp(0.0, max)))
+}
+
+/// First assessment and `operator(c_i, a_i)` label indexes, with importances mapped to the
+/// assessments domain.
+fn transformed<'a, 'domain, T, H, U, G>(
+    valuations: &'a [WeightedAssessment<'domain, '_, T, H, U, G>],
+    operator: ImportanceOperator,
+) -> Result<(&'a Single<'domain, T, H>, Vec<usize>), AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+    U: LabelMembership + PartialEq,
+    G: DomainHandle<Qualitative<U>>,
+{
+    let (first, first_importance) = valuations.first().ok_or(AggregationError::Empty)?;
+    let g = first.cardinality() - 1;
+    let importance_g = first_importance.cardinality() - 1;
+    let mut indexes = Vec::with_capacity(valuations.len());
+    for (index, (assessment, importance)) in valuations.iter().enumerate() {
+        if first.check_same_domain(assessment).is_err()
+            || first_importance.check_same_domain(importance).is_err()
+        {
+            return Err(AggregationError::DomainMismatch { index });
+        }
+        let importance = assessment.with_index(rescale_index(importance.index(), importance_g, g));
+        indexes.push(operator.apply(&importance, assessment)?.index());
+    }
+    Ok((first, indexes))
+}
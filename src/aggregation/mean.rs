@@ -0,0 +1,175 @@
+use crate::aggregation::{check_weights, saturated_delta, AggregationError};
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::LabelMembership;
+use crate::valuation::TwoTuple;
+
+/// 2-tuple arithmetic mean, Δ(Σ Δ<sup>-1</sup>(x<sub>i</sub>) / n).
+///
+/// # Arguments
+/// * `valuations`: Valuations over the same domain.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::arithmetic_mean;
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(),
+/// ];
+///
+/// assert_eq!(arithmetic_mean(&valuations).unwrap().to_string(), "(c, -0.03)");
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// ```
+/// # use assessment::aggregation::{arithmetic_mean, AggregationError};
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::valuation::TwoTuple;
+/// let valuations: [TwoTuple<Trapezoidal>; 0] = [];
+///
+/// assert_eq!(arithmetic_mean(&valuations), Err(AggregationError::Empty));
+/// ```
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// ```
+/// # use assessment::aggregation::{arithmetic_mean, AggregationError};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+///     TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap(),
+/// ];
+///
+/// assert_eq!(arithmetic_mean(&valuations), Err(AggregationError::DomainMismatch { index: 1 }));
+/// ```
+pub fn arithmetic_mean<'domain, T, H>(
+    valuations: &[TwoTuple<'domain, T, H>],
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    let weight = 1.0 / valuations.len() as f32;
+    weighted_sum(valuations.iter().map(|valuation| (valuation, weight)))
+}
+
+/// 2-tuple weighted average, Δ(Σ w<sub>i</sub> · Δ<sup>-1</sup>(x<sub>i</sub>)).
+///
+/// # Arguments
+/// * `valuations`: Pairs of valuation and weight, with valuations over the same domain and
+///   non negative weights summing up to 1.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::weighted_average;
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     (TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(), 0.5),
+///     (TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(), 0.3),
+///     (TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(), 0.2),
+/// ];
+///
+/// assert_eq!(weighted_average(&valuations).unwrap().to_string(), "(c, -0.20)");
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// ```
+/// # use assessment::aggregation::{weighted_average, AggregationError};
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::valuation::TwoTuple;
+/// let valuations: [(TwoTuple<Trapezoidal>, f32); 0] = [];
+///
+/// assert_eq!(weighted_average(&valuations), Err(AggregationError::Empty));
+/// ```
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidWeight**: If a weight is negative or non finite.
+///
+/// ```
+/// # use assessment::aggregation::{weighted_average, AggregationError};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuations = [
+///     (TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(), 1.5),
+///     (TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap(), -0.5),
+/// ];
+///
+/// assert_eq!(
+///     weighted_average(&valuations),
+///     Err(AggregationError::InvalidWeight { index: 1, weight: -0.5 })
+/// );
+/// ```
+///
+/// **AggregationError::InvalidWeightsSum**: If weights don't sum up to 1.
+///
+/// ```
+/// # use assessment::aggregation::{weighted_average, AggregationError};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuations = [
+///     (TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(), 0.5),
+///     (TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap(), 0.25),
+/// ];
+///
+/// assert_eq!(
+///     weighted_average(&valuations),
+///     Err(AggregationError::InvalidWeightsSum { sum: 0.75 })
+/// );
+/// ```
+pub fn weighted_average<'domain, T, H>(
+    valuations: &[(TwoTuple<'domain, T, H>, f32)],
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    if valuations.is_empty() {
+        return Err(AggregationError::Empty);
+    }
+    check_weights(valuations.iter().map(|(_, weight)| *weight))?;
+    weighted_sum(
+        valuations
+            .iter()
+            .map(|(valuation, weight)| (valuation, *weight)),
+    )
+}
+
+/// Δ(Σ w<sub>i</sub> · Δ<sup>-1</sup>(x<sub>i</sub>)), with `beta` saturated to the domain range
+/// to absorb rounding errors.
+fn weighted_sum<'a, 'domain: 'a, T, H>(
+    valuations: impl IntoIterator<Item = (&'a TwoTuple<'domain, T, H>, f32)>,
+) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+where
+    T: LabelMembership + PartialEq + 'a,
+    H: DomainHandle<Qualitative<T>> + 'a,
+{
+    let mut valuations = valuations.into_iter();
+    let (first, weight) = valuations.next().ok_or(AggregationError::Empty)?;
+    let mut beta = weight * first.inverse_delta();
+    for (index, (valuation, weight)) in valuations.enumerate() {
+        if first.check_same_domain(valuation).is_err() {
+            return Err(AggregationError::DomainMismatch { index: index + 1 });
+        }
+        beta += weight * valuation.inverse_delta();
+    }
+    Ok(saturated_delta(first, beta as f64))
+}
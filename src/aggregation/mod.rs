@@ -0,0 +1,312 @@
+//! Aggregation operators.
+
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::LabelMembership;
+use crate::utilities;
+use crate::valuation::{Linguistic, TwoTuple};
+use std::fmt::{Display, Formatter};
+
+pub use bonferroni::{
+    bonferroni_mean, heronian_mean, weighted_bonferroni_mean, weighted_heronian_mean,
+};
+pub use fuzzy_measure::FuzzyMeasure;
+pub use heterogeneous::{HeterogeneousError, HeterogeneousPipeline};
+pub use linguistic_weighted::{lwa, lwa_two_tuple, lwc, lwd, ImportanceOperator};
+pub use mean::{arithmetic_mean, weighted_average};
+pub use owa::{OwaWeights, Quantifier};
+pub use power::{power_average, power_owa, Support};
+pub use quasi_arithmetic::Generator;
+pub use sugeno::{QualitativeCapacity, SharedQualitativeCapacity};
+
+/// Bonferroni and Heronian means.
+pub mod bonferroni;
+
+/// Fuzzy measures and Choquet integral.
+pub mod fuzzy_measure;
+
+/// Heterogeneous information aggregation.
+pub mod heterogeneous;
+
+/// Linguistic weighted operators with linguistic importance degrees.
+pub mod linguistic_weighted;
+
+/// Arithmetic means.
+pub mod mean;
+
+/// Ordered weighted averaging operators.
+pub mod owa;
+
+/// Power aggregation operators.
+pub mod power;
+
+/// Quasi-arithmetic means (geometric, harmonic, power...).
+pub mod quasi_arithmetic;
+
+/// Qualitative capacities and Sugeno integral.
+pub mod sugeno;
+
+/// Aggregation errors types.
+#[derive(Debug, PartialEq)]
+pub enum AggregationError {
+    /// No values to aggregate.
+    Empty,
+    /// Negative or non finite weight.
+    InvalidWeight { index: usize, weight: f32 },
+    /// Weights don't sum up to 1.
+    InvalidWeightsSum { sum: f32 },
+    /// Value defined over a different domain than the first one.
+    DomainMismatch { index: usize },
+    /// Number of values differs from the number of weights.
+    LengthMismatch { expected: usize, found: usize },
+    /// Invalid linguistic quantifier parameters.
+    InvalidQuantifier { a: f32, b: f32 },
+    /// Orness outside the range [0, 1].
+    InvalidOrness { orness: f32 },
+    /// Exponential smoothing parameter outside the range [0, 1].
+    InvalidSmoothingParameter { alpha: f32 },
+    /// Fuzzy measure of the empty set other than 0 or of all criteria other than 1.
+    InvalidMeasureBoundaries { empty: f32, full: f32 },
+    /// Fuzzy measure of a subset greater than the one of its superset.
+    NonMonotonicMeasure {
+        subset: Vec<usize>,
+        superset: Vec<usize>,
+    },
+    /// Density outside the range [0, 1].
+    InvalidDensity { index: usize, density: f32 },
+    /// Invalid subset of criteria.
+    InvalidSubset { subset: Vec<usize> },
+    /// Number of criteria greater than [fuzzy_measure::MAX_CRITERIA].
+    TooManyCriteria { criteria: usize },
+    /// Non finite fuzzy measure or outside the range [0, 1].
+    InvalidMeasureValue { subset: Vec<usize>, measure: f32 },
+    /// Qualitative capacity label out of domain range.
+    InvalidCapacityLabel { subset: Vec<usize>, index: usize },
+    /// Qualitative capacity of the empty set other than the first label or of all criteria
+    /// other than the last one.
+    InvalidCapacityBoundaries { empty: usize, full: usize },
+    /// Capacity label mapped out of the valuations domain range or below the previous one.
+    InvalidScaleMap { index: usize, label: usize },
+    /// Negative or non finite mean exponents, or both of them 0.
+    InvalidMeanParameters { p: f32, q: f32 },
+    /// Support outside the range [0, 1].
+    InvalidSupport { distance: f32, support: f32 },
+    /// Null or non finite power mean exponent.
+    InvalidPower { lambda: f32 },
+    /// Undefined or non finite mean.
+    UndefinedMean,
+    /// Order inducing key not comparable with itself or a previous one (e.g., NaN).
+    IncomparableKey { index: usize },
+}
+
+impl Display for AggregationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use AggregationError::*;
+        match &self {
+            Empty => write!(f, "No values to aggregate."),
+            InvalidWeight { index, weight } => {
+                write!(
+                    f,
+                    "Invalid weight {} at position {}. Weights should be finite and non negative.",
+                    weight, index
+                )
+            }
+            InvalidWeightsSum { sum } => {
+                write!(f, "Weights sum up to {} instead of 1.", sum)
+            }
+            DomainMismatch { index } => {
+                write!(
+                    f,
+                    "Value at position {} is defined over a different domain than the first one.",
+                    index
+                )
+            }
+            LengthMismatch { expected, found } => {
+                write!(f, "Expected {} values, found {}.", expected, found)
+            }
+            InvalidQuantifier { a, b } => {
+                write!(
+                    f,
+                    "Invalid quantifier parameters ({}, {}). They should satisfy 0 <= a < b <= 1.",
+                    a, b
+                )
+            }
+            InvalidOrness { orness } => {
+                write!(
+                    f,
+                    "Invalid orness {}. Value should be in range [0, 1].",
+                    orness
+                )
+            }
+            InvalidSmoothingParameter { alpha } => {
+                write!(
+                    f,
+                    "Invalid smoothing parameter {}. Value should be in range [0, 1].",
+                    alpha
+                )
+            }
+            InvalidMeasureBoundaries { empty, full } => {
+                write!(
+                    f,
+                    "Invalid fuzzy measure boundaries (empty set == {}, all criteria == {}). They should be 0 and 1.",
+                    empty, full
+                )
+            }
+            NonMonotonicMeasure { subset, superset } => {
+                write!(
+                    f,
+                    "Fuzzy measure of {:?} is greater than the one of {:?}.",
+                    subset, superset
+                )
+            }
+            InvalidDensity { index, density } => {
+                write!(
+                    f,
+                    "Invalid density {} at position {}. Densities should be in range [0, 1], with at least two positive ones or summing up to 1.",
+                    density, index
+                )
+            }
+            InvalidSubset { subset } => {
+                write!(f, "Invalid subset of criteria {:?}.", subset)
+            }
+            TooManyCriteria { criteria } => {
+                write!(
+                    f,
+                    "Too many criteria ({}). At most {} are supported.",
+                    criteria,
+                    fuzzy_measure::MAX_CRITERIA
+                )
+            }
+            InvalidMeasureValue { subset, measure } => {
+                write!(
+                    f,
+                    "Invalid fuzzy measure {} of {:?}. Value should be in range [0, 1].",
+                    measure, subset
+                )
+            }
+            InvalidCapacityLabel { subset, index } => {
+                write!(
+                    f,
+                    "Invalid label index {} measuring subset of criteria {:?}.",
+                    index, subset
+                )
+            }
+            InvalidCapacityBoundaries { empty, full } => {
+                write!(
+                    f,
+                    "Invalid capacity boundaries (empty set == {}, all criteria == {}). They should be the first and last labels.",
+                    empty, full
+                )
+            }
+            InvalidScaleMap { index, label } => {
+                write!(
+                    f,
+                    "Capacity label {} is mapped to {}. Labels should be mapped to non decreasing indexes in valuations domain range.",
+                    index, label
+                )
+            }
+            InvalidMeanParameters { p, q } => {
+                write!(
+                    f,
+                    "Invalid mean parameters (p == {}, q == {}). They should be finite, non negative and not both 0.",
+                    p, q
+                )
+            }
+            InvalidSupport { distance, support } => {
+                write!(
+                    f,
+                    "Invalid support {} for distance {}. Value should be in range [0, 1].",
+                    support, distance
+                )
+            }
+            InvalidPower { lambda } => {
+                write!(
+                    f,
+                    "Invalid power {}. Value should be finite and other than 0.",
+                    lambda
+                )
+            }
+            UndefinedMean => write!(f, "Mean is undefined for the given values."),
+            IncomparableKey { index } => {
+                write!(
+                    f,
+                    "Order inducing key at position {} can't be compared with the other ones.",
+                    index
+                )
+            }
+        }
+    }
+}
+
+/// Checks that `weights` are non negative and sum up to 1 (with 4 decimals).
+pub(crate) fn check_weights(
+    weights: impl IntoIterator<Item = f32>,
+) -> Result<(), AggregationError> {
+    let mut sum = 0.0;
+    for (index, weight) in weights.into_iter().enumerate() {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(AggregationError::InvalidWeight { index, weight });
+        }
+        sum += weight;
+    }
+    if utilities::math::approx_equal_f32(sum, 1.0, 4) {
+        Ok(())
+    } else {
+        Err(AggregationError::InvalidWeightsSum { sum })
+    }
+}
+
+/// Label index with the same relative position as `index` when the maximum index changes from
+/// `from` to `to`, rounded to the nearest one.
+///
+/// A single label (`from == 0`) is mapped to the maximum index.
+pub(crate) fn rescale_index(index: usize, from: usize, to: usize) -> usize {
+    if from == 0 {
+        to
+    } else {
+        (2 * index * to + from) / (2 * from)
+    }
+}
+
+/// Symbolic values (Δ<sup>-1</sup>) of 2-tuple `valuations` and the first of them, checking that
+/// all of them are defined over the same domain.
+pub(crate) fn symbolic_values<'a, 'domain: 'a, T, H>(
+    valuations: impl IntoIterator<Item = &'a TwoTuple<'domain, T, H>>,
+) -> Result<(&'a TwoTuple<'domain, T, H>, Vec<f64>), AggregationError>
+where
+    T: LabelMembership + PartialEq + 'a,
+    H: DomainHandle<Qualitative<T>> + 'a,
+{
+    let mut valuations = valuations.into_iter();
+    let first = valuations.next().ok_or(AggregationError::Empty)?;
+    let mut values = vec![first.inverse_delta() as f64];
+    for (index, valuation) in valuations.enumerate() {
+        if first.check_same_domain(valuation).is_err() {
+            return Err(AggregationError::DomainMismatch { index: index + 1 });
+        }
+        values.push(valuation.inverse_delta() as f64);
+    }
+    Ok((first, values))
+}
+
+/// Valuation over the domain of `valuation` given its symbolic value (Δ), saturated to the
+/// domain range to absorb rounding errors.
+pub(crate) fn saturated_delta<'domain, T, H>(
+    valuation: &TwoTuple<'domain, T, H>,
+    beta: f64,
+) -> TwoTuple<'domain, T, H>
+where
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>>,
+{
+    let max = (valuation.cardinality() - 1) as f64;
+    valuation.delta_in_domain(beta.clamp(0.0, max) as f32)
+}
+
+/// Granularity of the domain of a 2-tuple valuation, used to normalize its symbolic value.
+pub(crate) fn granularity<T, H>(valuation: &TwoTuple<T, H>) -> f64
+where
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>>,
+{
+    ((valuation.cardinality() - 1) as f64).max(1.0)
+}
@@ -0,0 +1,1169 @@
+use crate::aggregation::{
+    check_weights, granularity, saturated_delta, symbolic_values, AggregationError, Generator,
+};
+use crate::domain::quantitative::NORMALIZATION_DOMAIN;
+use crate::domain::{DomainHandle, Qualitative, Quantitative};
+use crate::fuzzy::membership::Trapezoidal;
+use crate::fuzzy::LabelMembership;
+use crate::valuation::{Interval, Linguistic, Numeric, Single, TwoTuple, Unified};
+use std::cmp::Ordering;
+
+/// Regular increasing monotone (RIM) linguistic quantifiers.
+///
+/// Each quantifier `Q` is defined by parameters `0 <= a < b <= 1` as `Q(r) = 0` if `r < a`,
+/// `Q(r) = (r - a) / (b - a)` if `a <= r <= b` and `Q(r) = 1` if `r > b`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Quantifier {
+    /// *Most*, with `(a, b) = (0.3, 0.8)`.
+    Most,
+    /// *At least half*, with `(a, b) = (0.0, 0.5)`.
+    AtLeastHalf,
+    /// *As many as possible*, with `(a, b) = (0.5, 1.0)`.
+    AsManyAsPossible,
+    /// Quantifier with custom parameters.
+    Linear { a: f32, b: f32 },
+}
+
+impl Quantifier {
+    /// Returns quantifier parameters `(a, b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Quantifier;
+    /// assert_eq!(Quantifier::Most.parameters(), (0.3, 0.8));
+    /// assert_eq!(Quantifier::Linear { a: 0.2, b: 0.4 }.parameters(), (0.2, 0.4));
+    /// ```
+    pub fn parameters(&self) -> (f32, f32) {
+        use Quantifier::*;
+        match self {
+            Most => (0.3, 0.8),
+            AtLeastHalf => (0.0, 0.5),
+            AsManyAsPossible => (0.5, 1.0),
+            Linear { a, b } => (*a, *b),
+        }
+    }
+
+    /// Degree in which proportion `r` satisfies the quantifier.
+    ///
+    /// # Arguments
+    /// * `r`: Proportion, in range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Quantifier;
+    /// for (r, expected) in [(0.0, 0.0), (0.25, 0.5), (0.5, 1.0), (0.75, 1.0)] {
+    ///     assert_eq!(Quantifier::AtLeastHalf.membership(r), expected);
+    /// }
+    /// ```
+    pub fn membership(&self, r: f32) -> f32 {
+        let (a, b) = self.parameters();
+        if r < a {
+            0.0
+        } else if r > b {
+            1.0
+        } else {
+            (r - a) / (b - a)
+        }
+    }
+
+    /// Checks that quantifier parameters satisfy `0 <= a < b <= 1`.
+    pub(crate) fn check(&self) -> Result<(), AggregationError> {
+        let (a, b) = self.parameters();
+        if 0.0 <= a && a < b && b <= 1.0 {
+            Ok(())
+        } else {
+            Err(AggregationError::InvalidQuantifier { a, b })
+        }
+    }
+}
+
+/// Ordered weighted averaging (OWA) weights.
+///
+/// Weight `w[i]` is applied to the `i`-th greatest value.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OwaWeights {
+    weights: Vec<f32>,
+}
+
+impl OwaWeights {
+    /// Creates new weights.
+    ///
+    /// # Arguments
+    /// * `weights`: Non negative weights summing up to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// assert_eq!(OwaWeights::new(vec![0.5, 0.3, 0.2]).unwrap().weights(), &[0.5, 0.3, 0.2]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If there are no weights.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// assert_eq!(OwaWeights::new(vec![]), Err(AggregationError::Empty));
+    /// ```
+    ///
+    /// **AggregationError::InvalidWeight**: If a weight is negative or non finite.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// assert_eq!(
+    ///     OwaWeights::new(vec![1.5, -0.5]),
+    ///     Err(AggregationError::InvalidWeight { index: 1, weight: -0.5 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::InvalidWeightsSum**: If weights don't sum up to 1.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// assert_eq!(
+    ///     OwaWeights::new(vec![0.5, 0.25]),
+    ///     Err(AggregationError::InvalidWeightsSum { sum: 0.75 })
+    /// );
+    /// ```
+    pub fn new(weights: Vec<f32>) -> Result<Self, AggregationError> {
+        if weights.is_empty() {
+            return Err(AggregationError::Empty);
+        }
+        check_weights(weights.iter().copied())?;
+        Ok(Self { weights })
+    }
+
+    /// Weights for `n` values given a RIM linguistic `quantifier`,
+    /// `w[i] = Q((i + 1) / n) - Q(i / n)`.
+    ///
+    /// # Arguments
+    /// * `quantifier`: Linguistic quantifier.
+    /// * `n`: Number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{OwaWeights, Quantifier};
+    /// # use assessment::utilities;
+    /// for (quantifier, expected) in [
+    ///     (Quantifier::Most, vec![0.0, 0.4, 0.5, 0.1]),
+    ///     (Quantifier::AtLeastHalf, vec![0.5, 0.5, 0.0, 0.0]),
+    ///     (Quantifier::AsManyAsPossible, vec![0.0, 0.0, 0.5, 0.5]),
+    /// ] {
+    ///     let weights = OwaWeights::from_quantifier(quantifier, 4).unwrap();
+    ///     assert_eq!(
+    ///         weights.weights().iter().map(|w| utilities::math::round_f32(*w, 4)).collect::<Vec<_>>(),
+    ///         expected
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If `n == 0`.
+    ///
+    /// **AggregationError::InvalidQuantifier**: If quantifier parameters don't satisfy
+    /// `0 <= a < b <= 1`.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights, Quantifier};
+    /// assert_eq!(
+    ///     OwaWeights::from_quantifier(Quantifier::Linear { a: 0.6, b: 0.4 }, 3),
+    ///     Err(AggregationError::InvalidQuantifier { a: 0.6, b: 0.4 })
+    /// );
+    /// ```
+    pub fn from_quantifier(quantifier: Quantifier, n: usize) -> Result<Self, AggregationError> {
+        quantifier.check()?;
+        let proportion = |i: usize| quantifier.membership(i as f32 / n as f32);
+        Self::new((0..n).map(|i| proportion(i + 1) - proportion(i)).collect())
+    }
+
+    /// Maximal entropy weights for `n` values with a given `orness` (O'Hagan's method).
+    ///
+    /// Weights are the geometric sequence with the required orness, obtained numerically.
+    ///
+    /// # Arguments
+    /// * `n`: Number of values.
+    /// * `orness`: Orness degree, in range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::utilities;
+    /// let weights = OwaWeights::max_entropy(3, 0.7).unwrap();
+    /// assert_eq!(
+    ///     weights.weights().iter().map(|w| utilities::math::round_f32(*w, 3)).collect::<Vec<_>>(),
+    ///     vec![0.554, 0.292, 0.154]
+    /// );
+    /// assert!(utilities::math::approx_equal_f32(weights.orness(), 0.7, 4));
+    ///
+    /// assert_eq!(OwaWeights::max_entropy(4, 0.5).unwrap().weights(), &[0.25; 4]);
+    /// assert_eq!(OwaWeights::max_entropy(3, 1.0).unwrap().weights(), &[1.0, 0.0, 0.0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If `n == 0`.
+    ///
+    /// **AggregationError::InvalidOrness**: If `orness` is outside the range `[0, 1]`.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// assert_eq!(
+    ///     OwaWeights::max_entropy(3, 1.2),
+    ///     Err(AggregationError::InvalidOrness { orness: 1.2 })
+    /// );
+    /// ```
+    pub fn max_entropy(n: usize, orness: f32) -> Result<Self, AggregationError> {
+        if !(0.0..=1.0).contains(&orness) {
+            return Err(AggregationError::InvalidOrness { orness });
+        }
+        if n <= 1 || orness == 0.5 {
+            return Self::new(vec![1.0 / n as f32; n]);
+        }
+        if orness == 0.0 || orness == 1.0 {
+            let mut weights = vec![0.0; n];
+            weights[if orness == 1.0 { 0 } else { n - 1 }] = 1.0;
+            return Self::new(weights);
+        }
+
+        // Orness decreases with the ratio of the sequence, searched by bisection on its logarithm
+        let (mut low, mut high) = (-50.0, 50.0);
+        let mut weights = geometric_weights(n, 0.0);
+        for _ in 0..100 {
+            let middle = (low + high) / 2.0;
+            weights = geometric_weights(n, middle);
+            if orness_of(&weights) > orness as f64 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Self::new(weights.iter().map(|weight| *weight as f32).collect())
+    }
+
+    /// Exponential smoothing weights for `n` values (Filev and Yager).
+    ///
+    /// Optimistic weights are `(α, α(1-α), ..., α(1-α)ⁿ⁻², (1-α)ⁿ⁻¹)` and pessimistic ones are
+    /// `(αⁿ⁻¹, (1-α)αⁿ⁻², ..., (1-α)α, 1-α)`.
+    ///
+    /// # Arguments
+    /// * `n`: Number of values.
+    /// * `alpha`: Smoothing parameter, in range `[0, 1]`.
+    /// * `optimistic`: Whether to generate optimistic or pessimistic weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::utilities;
+    /// for (optimistic, expected) in [
+    ///     (true, vec![0.4, 0.24, 0.144, 0.216]),
+    ///     (false, vec![0.064, 0.096, 0.24, 0.6]),
+    /// ] {
+    ///     let weights = OwaWeights::exponential(4, 0.4, optimistic).unwrap();
+    ///     assert_eq!(
+    ///         weights.weights().iter().map(|w| utilities::math::round_f32(*w, 4)).collect::<Vec<_>>(),
+    ///         expected
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If `n == 0`.
+    ///
+    /// **AggregationError::InvalidSmoothingParameter**: If `alpha` is outside the range `[0, 1]`.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// assert_eq!(
+    ///     OwaWeights::exponential(3, -0.1, true),
+    ///     Err(AggregationError::InvalidSmoothingParameter { alpha: -0.1 })
+    /// );
+    /// ```
+    pub fn exponential(n: usize, alpha: f32, optimistic: bool) -> Result<Self, AggregationError> {
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(AggregationError::InvalidSmoothingParameter { alpha });
+        }
+        let (first, rest) = if optimistic {
+            (alpha, 1.0 - alpha)
+        } else {
+            (1.0 - alpha, alpha)
+        };
+        let mut weights: Vec<f32> = (0..n)
+            .map(|i| {
+                if i + 1 == n {
+                    rest.powi(i as i32)
+                } else {
+                    first * rest.powi(i as i32)
+                }
+            })
+            .collect();
+        if !optimistic {
+            weights.reverse();
+        }
+        Self::new(weights)
+    }
+
+    /// Returns weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// assert_eq!(OwaWeights::new(vec![0.5, 0.5]).unwrap().weights(), &[0.5, 0.5]);
+    /// ```
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    /// Orness degree, `Σ (n - i - 1) · w[i] / (n - 1)`.
+    ///
+    /// It is 1 for the maximum, 0 for the minimum and 0.5 for a single weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{OwaWeights, Quantifier};
+    /// # use assessment::utilities;
+    /// for (weights, orness) in [
+    ///     (vec![1.0, 0.0, 0.0], 1.0),
+    ///     (vec![0.0, 0.0, 1.0], 0.0),
+    ///     (vec![0.25, 0.25, 0.25, 0.25], 0.5),
+    ///     (vec![0.0, 0.4, 0.5, 0.1], 0.4333),
+    ///     (vec![1.0], 0.5),
+    /// ] {
+    ///     let weights = OwaWeights::new(weights).unwrap();
+    ///     assert!(utilities::math::approx_equal_f32(weights.orness(), orness, 4));
+    ///     assert!(utilities::math::approx_equal_f32(weights.andness(), 1.0 - orness, 4));
+    /// }
+    /// ```
+    pub fn orness(&self) -> f32 {
+        orness_of(
+            &self
+                .weights
+                .iter()
+                .map(|weight| *weight as f64)
+                .collect::<Vec<f64>>(),
+        ) as f32
+    }
+
+    /// Andness degree, `1 - orness`.
+    ///
+    /// See [OwaWeights::orness].
+    pub fn andness(&self) -> f32 {
+        1.0 - self.orness()
+    }
+
+    /// Dispersion (entropy) of the weights, `-Σ w[i] · ln(w[i])`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::utilities;
+    /// for (weights, dispersion) in [
+    ///     (vec![1.0, 0.0, 0.0], 0.0),
+    ///     (vec![0.25, 0.25, 0.25, 0.25], 4.0f32.ln()),
+    ///     (vec![0.0, 0.4, 0.5, 0.1], 0.9433),
+    /// ] {
+    ///     let weights = OwaWeights::new(weights).unwrap();
+    ///     assert!(utilities::math::approx_equal_f32(weights.dispersion(), dispersion, 4));
+    /// }
+    /// ```
+    pub fn dispersion(&self) -> f32 {
+        -self
+            .weights
+            .iter()
+            .filter(|weight| **weight > 0.0)
+            .map(|weight| weight * weight.ln())
+            .sum::<f32>()
+    }
+
+    /// OWA aggregation of `values`.
+    ///
+    /// # Arguments
+    /// * `values`: Values to aggregate, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// let weights = OwaWeights::new(vec![0.5, 0.25, 0.25]).unwrap();
+    /// assert_eq!(weights.aggregate(&[1.0, 3.0, 2.0]), Ok(2.25));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many values as weights.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// let weights = OwaWeights::new(vec![0.5, 0.5]).unwrap();
+    /// assert_eq!(
+    ///     weights.aggregate(&[1.0, 3.0, 2.0]),
+    ///     Err(AggregationError::LengthMismatch { expected: 2, found: 3 })
+    /// );
+    /// ```
+    pub fn aggregate(&self, values: &[f64]) -> Result<f64, AggregationError> {
+        if values.len() != self.weights.len() {
+            return Err(AggregationError::LengthMismatch {
+                expected: self.weights.len(),
+                found: values.len(),
+            });
+        }
+        let mut values = values.to_vec();
+        values.sort_by(|a, b| b.total_cmp(a));
+        Ok(values
+            .iter()
+            .zip(&self.weights)
+            .map(|(value, weight)| value * *weight as f64)
+            .sum())
+    }
+
+    /// 2-tuple OWA aggregation, Δ(Σ w[i] · β<sub>σ(i)</sub>), where β<sub>σ(i)</sub> is the
+    /// `i`-th greatest Δ<sup>-1</sup>(x<sub>j</sub>).
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations over the same domain, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 4, 0.0).unwrap(),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.4, 0.3, 0.2, 0.1]).unwrap();
+    ///
+    /// assert_eq!(weights.aggregate_two_tuple(&valuations).unwrap().to_string(), "(d, -0.08)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many valuations as weights.
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+    ///     TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap(),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.5, 0.5]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     weights.aggregate_two_tuple(&valuations),
+    ///     Err(AggregationError::DomainMismatch { index: 1 })
+    /// );
+    /// ```
+    pub fn aggregate_two_tuple<'domain, T, H>(
+        &self,
+        valuations: &[TwoTuple<'domain, T, H>],
+    ) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+    where
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        self.first(valuations)?;
+        let (first, values) = symbolic_values(valuations)?;
+        Ok(saturated_delta(first, self.aggregate(&values)?))
+    }
+
+    /// Numeric OWA aggregation of normalized values.
+    ///
+    /// Valuations are normalized in domain 0.0 to 1.0 (see [Numeric::normalize]), so they may
+    /// be defined over different domains.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations to aggregate, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::domain::quantitative::NORMALIZATION_DOMAIN;
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let valuations = [2.0, 8.0, 5.0, 6.0].map(|value| Numeric::new(&domain, value).unwrap());
+    /// let weights = OwaWeights::new(vec![0.4, 0.3, 0.2, 0.1]).unwrap();
+    ///
+    /// let result = weights.aggregate_numeric(&valuations).unwrap();
+    /// assert!(utilities::math::approx_equal_f64(result.value(), 0.62, 5));
+    /// assert_eq!(*result.domain(), NORMALIZATION_DOMAIN);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many valuations as weights.
+    pub fn aggregate_numeric<'domain, H>(
+        &self,
+        valuations: &[Numeric<'domain, f64, H>],
+    ) -> Result<Numeric<'static, f64>, AggregationError>
+    where
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        let values: Vec<f64> = valuations
+            .iter()
+            .map(|valuation| valuation.normalize().value())
+            .collect();
+        let value = self.aggregate(&values)?;
+        Ok(Numeric::new(&NORMALIZATION_DOMAIN, value.clamp(0.0, 1.0)).unwrap())
+    }
+
+    /// OWA aggregation of unified valuations through their `chi` values (see [Unified::chi]).
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations over the same BLTS domain, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::valuation::Unified;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let valuations = [
+    ///     Unified::new(&domain, vec![0.0, 1.0, 0.0]).unwrap(),
+    ///     Unified::new(&domain, vec![0.0, 0.0, 1.0]).unwrap(),
+    ///     Unified::new(&domain, vec![1.0, 0.0, 0.0]).unwrap(),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.5, 0.3, 0.2]).unwrap();
+    ///
+    /// assert_eq!(weights.aggregate_unified(&valuations).unwrap().to_string(), "(b, 0.30)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many valuations as weights.
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn aggregate_unified<'domain>(
+        &self,
+        valuations: &[Unified<'domain>],
+    ) -> Result<TwoTuple<'domain, Trapezoidal>, AggregationError> {
+        let domain = self.first(valuations)?.domain();
+        for (index, valuation) in valuations.iter().enumerate() {
+            if *valuation.domain() != *domain {
+                return Err(AggregationError::DomainMismatch { index });
+            }
+        }
+        let values: Vec<f64> = valuations
+            .iter()
+            .map(|valuation| valuation.chi() as f64)
+            .collect();
+        let first = TwoTuple::delta_in(domain, 0.0);
+        Ok(saturated_delta(&first, self.aggregate(&values)?))
+    }
+
+    /// Generalized OWA (GOWA) aggregation, φ⁻¹(Σ w[i] · φ(b[i])), where b[i] is the `i`-th
+    /// greatest value and φ a quasi-arithmetic mean generator.
+    ///
+    /// [Generator::Power] leads to the GOWA operators in the literature (OWA with `lambda == 1`,
+    /// ordered weighted quadratic averaging with `lambda == 2`...), [Generator::Geometric] to the
+    /// ordered weighted geometric averaging and other generators to quasi-arithmetic OWA
+    /// operators.
+    ///
+    /// # Arguments
+    /// * `values`: Values to aggregate, as many as weights.
+    /// * `generator`: Quasi-arithmetic mean generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{Generator, OwaWeights};
+    /// # use assessment::utilities;
+    /// let weights = OwaWeights::new(vec![0.5, 0.25, 0.25]).unwrap();
+    /// let values = [0.1, 0.4, 0.2];
+    ///
+    /// for (generator, expected) in [
+    ///     (Generator::Power { lambda: 1.0 }, 0.275),
+    ///     (Generator::Power { lambda: 2.0 }, 0.3041),
+    ///     (Generator::Geometric, 0.2378),
+    /// ] {
+    ///     let result = weights.generalized_aggregate(&values, generator).unwrap();
+    ///     assert!(utilities::math::approx_equal_f64(result, expected, 4));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many values as weights.
+    ///
+    /// **AggregationError::InvalidPower**: If the power of a power mean is 0 or non finite.
+    ///
+    /// **AggregationError::UndefinedMean**: If the mean is undefined (or non finite) for the
+    /// given values.
+    pub fn generalized_aggregate(
+        &self,
+        values: &[f64],
+        generator: Generator,
+    ) -> Result<f64, AggregationError> {
+        self.first(values)?;
+        let mut values = values.to_vec();
+        values.sort_by(|a, b| b.total_cmp(a));
+        generator.weighted(values.into_iter().zip(self.weights.iter().copied()))
+    }
+
+    /// Numeric GOWA aggregation of normalized values (see [OwaWeights::generalized_aggregate]).
+    ///
+    /// Valuations are normalized in domain 0.0 to 1.0 (see [Numeric::normalize]), so they may
+    /// be defined over different domains. The result is expressed over the domain of the first
+    /// valuation.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations to aggregate, as many as weights.
+    /// * `generator`: Quasi-arithmetic mean generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{Generator, OwaWeights};
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let valuations = [1.0, 4.0, 2.0].map(|value| Numeric::new(&domain, value).unwrap());
+    /// let weights = OwaWeights::new(vec![0.5, 0.25, 0.25]).unwrap();
+    ///
+    /// let result = weights
+    ///     .generalized_aggregate_numeric(&valuations, Generator::Power { lambda: 2.0 })
+    ///     .unwrap();
+    /// assert!(utilities::math::approx_equal_f64(result.value(), 3.041, 3));
+    /// assert_eq!(*result.domain(), domain);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [OwaWeights::generalized_aggregate].
+    pub fn generalized_aggregate_numeric<'domain, H>(
+        &self,
+        valuations: &[Numeric<'domain, f64, H>],
+        generator: Generator,
+    ) -> Result<Numeric<'domain, f64, H>, AggregationError>
+    where
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        let first = self.first(valuations)?;
+        let values: Vec<f64> = valuations
+            .iter()
+            .map(|valuation| valuation.normalize().value())
+            .collect();
+        Ok(first.denormalize(self.generalized_aggregate(&values, generator)?))
+    }
+
+    /// Interval GOWA aggregation of normalized values (see
+    /// [OwaWeights::generalized_aggregate]), aggregating lower and upper limits separately.
+    ///
+    /// Valuations are normalized in domain 0.0 to 1.0 (see [Interval::normalize]), so they may
+    /// be defined over different domains. The result is expressed over the domain of the first
+    /// valuation.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations to aggregate, as many as weights.
+    /// * `generator`: Quasi-arithmetic mean generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{Generator, OwaWeights};
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::valuation::Interval;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let valuations = [
+    ///     Interval::new(&domain, 2.0, 4.0).unwrap(),
+    ///     Interval::new(&domain, 6.0, 8.0).unwrap(),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.75, 0.25]).unwrap();
+    ///
+    /// let (min, max) = weights
+    ///     .generalized_aggregate_interval(&valuations, Generator::Power { lambda: 1.0 })
+    ///     .unwrap()
+    ///     .value();
+    /// assert!(utilities::math::approx_equal_f64(min, 5.0, 4));
+    /// assert!(utilities::math::approx_equal_f64(max, 7.0, 4));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [OwaWeights::generalized_aggregate].
+    pub fn generalized_aggregate_interval<'domain, H>(
+        &self,
+        valuations: &[Interval<'domain, f64, H>],
+        generator: Generator,
+    ) -> Result<Interval<'domain, f64, H>, AggregationError>
+    where
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        let first = self.first(valuations)?;
+        let (mins, maxs): (Vec<f64>, Vec<f64>) = valuations
+            .iter()
+            .map(|valuation| valuation.normalize().value())
+            .unzip();
+        Ok(first.denormalize(
+            self.generalized_aggregate(&mins, generator)?,
+            self.generalized_aggregate(&maxs, generator)?,
+        ))
+    }
+
+    /// Generalized linguistic OWA (GLOWA) aggregation of 2-tuple valuations,
+    /// Δ(g · φ⁻¹(Σ w[i] · φ(β<sub>σ(i)</sub> / g))), where β<sub>σ(i)</sub> is the `i`-th greatest
+    /// Δ<sup>-1</sup>(x<sub>j</sub>) and `g` the domain granularity (see
+    /// [OwaWeights::generalized_aggregate]).
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations over the same domain, as many as weights.
+    /// * `generator`: Quasi-arithmetic mean generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{Generator, OwaWeights};
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 4, 0.0).unwrap(),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.4, 0.3, 0.2, 0.1]).unwrap();
+    ///
+    /// for (generator, expected) in [
+    ///     (Generator::Power { lambda: 1.0 }, "(d, -0.08)"),
+    ///     (Generator::Power { lambda: 2.0 }, "(d, 0.07)"),
+    ///     (Generator::Geometric, "(d, -0.26)"),
+    /// ] {
+    ///     let result = weights.generalized_aggregate_two_tuple(&valuations, generator).unwrap();
+    ///     assert_eq!(result.to_string(), expected);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// See [OwaWeights::generalized_aggregate] for the rest of them.
+    pub fn generalized_aggregate_two_tuple<'domain, T, H>(
+        &self,
+        valuations: &[TwoTuple<'domain, T, H>],
+        generator: Generator,
+    ) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+    where
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        self.first(valuations)?;
+        let (first, values) = symbolic_values(valuations)?;
+        let g = granularity(first);
+        let values: Vec<f64> = values.iter().map(|value| value / g).collect();
+        let value = self.generalized_aggregate(&values, generator)?;
+        Ok(saturated_delta(first, value * g))
+    }
+
+    /// Induced OWA (IOWA) aggregation, Σ w[i] · b[i], where b[i] is the value of the pair with
+    /// the `i`-th greatest order inducing key.
+    ///
+    /// Values whose keys are tied are replaced by their average, so the result doesn't depend on
+    /// the order they are given.
+    ///
+    /// # Arguments
+    /// * `pairs`: Pairs of order inducing key and value, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// let weights = OwaWeights::new(vec![0.5, 0.25, 0.25]).unwrap();
+    /// assert_eq!(weights.induced_aggregate(&[(3, 1.0), (1, 3.0), (2, 2.0)]), Ok(1.75));
+    /// assert_eq!(weights.induced_aggregate(&[(1, 1.0), (1, 3.0), (2, 2.0)]), Ok(2.0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many pairs as weights.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// let weights = OwaWeights::new(vec![0.5, 0.5]).unwrap();
+    /// assert_eq!(
+    ///     weights.induced_aggregate(&[(1, 1.0)]),
+    ///     Err(AggregationError::LengthMismatch { expected: 2, found: 1 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::IncomparableKey**: If a key can't be compared with itself or a
+    /// previous one, so keys aren't totally ordered.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// let weights = OwaWeights::new(vec![0.5, 0.25, 0.25]).unwrap();
+    /// assert_eq!(
+    ///     weights.induced_aggregate(&[(0.3, 1.0), (f64::NAN, 3.0), (0.7, 2.0)]),
+    ///     Err(AggregationError::IncomparableKey { index: 1 })
+    /// );
+    /// ```
+    pub fn induced_aggregate<K: PartialOrd>(
+        &self,
+        pairs: &[(K, f64)],
+    ) -> Result<f64, AggregationError> {
+        self.first(pairs)?;
+        let values: Vec<f64> = pairs.iter().map(|(_, value)| *value).collect();
+        self.induced(pairs, &values)
+    }
+
+    /// 2-tuple IOWA aggregation, Δ(Σ w[i] · β[i]), where β[i] is Δ<sup>-1</sup> of the
+    /// valuation with the `i`-th greatest order inducing key.
+    ///
+    /// See [OwaWeights::induced_aggregate] for how ties are resolved.
+    ///
+    /// # Arguments
+    /// * `pairs`: Pairs of order inducing key and valuation, with valuations over the same domain
+    ///   and as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// // Valuations induced by expert reliability
+    /// let pairs = [
+    ///     (0.9, TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap()),
+    ///     (0.5, TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap()),
+    ///     (0.7, TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap()),
+    ///     (0.5, TwoTuple::new_by_label_index(&domain, 4, 0.0).unwrap()),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.4, 0.3, 0.2, 0.1]).unwrap();
+    ///
+    /// assert_eq!(weights.induced_aggregate_two_tuple(&pairs).unwrap().to_string(), "(c, 0.10)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many pairs as weights.
+    ///
+    /// **AggregationError::IncomparableKey**: If keys aren't totally ordered (see
+    /// [OwaWeights::induced_aggregate]).
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let pairs = [
+    ///     (1, TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap()),
+    ///     (2, TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap()),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.5, 0.5]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     weights.induced_aggregate_two_tuple(&pairs),
+    ///     Err(AggregationError::DomainMismatch { index: 1 })
+    /// );
+    /// ```
+    pub fn induced_aggregate_two_tuple<'domain, K, T, H>(
+        &self,
+        pairs: &[(K, TwoTuple<'domain, T, H>)],
+    ) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+    where
+        K: PartialOrd,
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        self.first(pairs)?;
+        let (first, values) = symbolic_values(pairs.iter().map(|(_, valuation)| valuation))?;
+        Ok(saturated_delta(first, self.induced(pairs, &values)?))
+    }
+
+    /// Numeric IOWA aggregation of normalized values.
+    ///
+    /// Valuations are normalized in domain 0.0 to 1.0 (see [Numeric::normalize]), so they may
+    /// be defined over different domains. See [OwaWeights::induced_aggregate] for how ties are
+    /// resolved.
+    ///
+    /// # Arguments
+    /// * `pairs`: Pairs of order inducing key and valuation, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::domain::quantitative::NORMALIZATION_DOMAIN;
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let pairs = [('c', 2.0), ('a', 8.0), ('b', 5.0), ('a', 6.0)]
+    ///     .map(|(key, value)| (key, Numeric::new(&domain, value).unwrap()));
+    /// let weights = OwaWeights::new(vec![0.4, 0.3, 0.2, 0.1]).unwrap();
+    ///
+    /// let result = weights.induced_aggregate_numeric(&pairs).unwrap();
+    /// assert!(utilities::math::approx_equal_f64(result.value(), 0.44, 5));
+    /// assert_eq!(*result.domain(), NORMALIZATION_DOMAIN);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many pairs as weights.
+    ///
+    /// **AggregationError::IncomparableKey**: If keys aren't totally ordered (see
+    /// [OwaWeights::induced_aggregate]).
+    pub fn induced_aggregate_numeric<'domain, K, H>(
+        &self,
+        pairs: &[(K, Numeric<'domain, f64, H>)],
+    ) -> Result<Numeric<'static, f64>, AggregationError>
+    where
+        K: PartialOrd,
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        self.first(pairs)?;
+        let values: Vec<f64> = pairs
+            .iter()
+            .map(|(_, valuation)| valuation.normalize().value())
+            .collect();
+        let value = self.induced(pairs, &values)?;
+        Ok(Numeric::new(&NORMALIZATION_DOMAIN, value.clamp(0.0, 1.0)).unwrap())
+    }
+
+    /// IOWA aggregation of unified valuations through their `chi` values (see [Unified::chi]).
+    ///
+    /// See [OwaWeights::induced_aggregate] for how ties are resolved.
+    ///
+    /// # Arguments
+    /// * `pairs`: Pairs of order inducing key and valuation, with valuations over the same BLTS
+    ///   domain and as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::valuation::Unified;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let pairs = [
+    ///     (2, Unified::new(&domain, vec![0.0, 1.0, 0.0]).unwrap()),
+    ///     (1, Unified::new(&domain, vec![0.0, 0.0, 1.0]).unwrap()),
+    ///     (3, Unified::new(&domain, vec![1.0, 0.0, 0.0]).unwrap()),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.5, 0.3, 0.2]).unwrap();
+    ///
+    /// assert_eq!(weights.induced_aggregate_unified(&pairs).unwrap().to_string(), "(b, -0.30)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many pairs as weights.
+    ///
+    /// **AggregationError::IncomparableKey**: If keys aren't totally ordered (see
+    /// [OwaWeights::induced_aggregate]).
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn induced_aggregate_unified<'domain, K: PartialOrd>(
+        &self,
+        pairs: &[(K, Unified<'domain>)],
+    ) -> Result<TwoTuple<'domain, Trapezoidal>, AggregationError> {
+        let domain = self.first(pairs)?.1.domain();
+        for (index, (_, valuation)) in pairs.iter().enumerate() {
+            if *valuation.domain() != *domain {
+                return Err(AggregationError::DomainMismatch { index });
+            }
+        }
+        let values: Vec<f64> = pairs
+            .iter()
+            .map(|(_, valuation)| valuation.chi() as f64)
+            .collect();
+        let first = TwoTuple::delta_in(domain, 0.0);
+        Ok(saturated_delta(&first, self.induced(pairs, &values)?))
+    }
+
+    /// Linguistic OWA (LOWA) aggregation, working directly on label indexes.
+    ///
+    /// Valuations are sorted in descending order and combined through the recursive convex
+    /// combination `C{w[i], b[i]} = w[0] ⊙ b[0] ⊕ (1 - w[0]) ⊙ C{w[h] / Σ w[k>0], b[h]}`, where two
+    /// labels `s_j >= s_i` are combined as `s_k`, with `k = i + round(w · (j - i))`.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations over the same domain, as many as weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::OwaWeights;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [1, 3, 2, 4].map(|index| Single::new_by_label_index(&domain, index).unwrap());
+    ///
+    /// for (weights, expected) in [
+    ///     (vec![0.4, 0.3, 0.2, 0.1], "d"),
+    ///     (vec![0.0, 0.0, 0.0, 1.0], "b"),
+    ///     (vec![0.25, 0.25, 0.25, 0.25], "d"),
+    /// ] {
+    ///     let weights = OwaWeights::new(weights).unwrap();
+    ///     assert_eq!(weights.aggregate_single(&valuations).unwrap().to_string(), expected);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't as many valuations as weights.
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, OwaWeights};
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [
+    ///     Single::new_by_label_index(&domain, 1).unwrap(),
+    ///     Single::new_by_label_index(&other, 1).unwrap(),
+    /// ];
+    /// let weights = OwaWeights::new(vec![0.5, 0.5]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     weights.aggregate_single(&valuations),
+    ///     Err(AggregationError::DomainMismatch { index: 1 })
+    /// );
+    /// ```
+    pub fn aggregate_single<'domain, T, H>(
+        &self,
+        valuations: &[Single<'domain, T, H>],
+    ) -> Result<Single<'domain, T, H>, AggregationError>
+    where
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        let first = self.first(valuations)?;
+        for (index, valuation) in valuations.iter().enumerate() {
+            if first.check_same_domain(valuation).is_err() {
+                return Err(AggregationError::DomainMismatch { index });
+            }
+        }
+        let result = self.convex_combination(valuations.iter().map(Single::index).collect())?;
+        Ok(first.with_index(result.min(first.cardinality() - 1)))
+    }
+
+    /// LOWA convex combination of label `indexes` (see [OwaWeights::aggregate_single]).
+    pub(crate) fn convex_combination(
+        &self,
+        mut indexes: Vec<usize>,
+    ) -> Result<usize, AggregationError> {
+        self.first(&indexes)?;
+        indexes.sort_by(|a, b| b.cmp(a));
+
+        // Convex combinations are solved from the innermost (lowest labels) outwards
+        let last = indexes.len() - 1;
+        let mut result = indexes[last];
+        let mut tail = self.weights[last];
+        for h in (0..last).rev() {
+            tail += self.weights[h];
+            let weight = if tail > 0.0 {
+                self.weights[h] / tail
+            } else {
+                1.0
+            };
+            result += (weight * (indexes[h] - result) as f32).round() as usize;
+        }
+        Ok(result)
+    }
+
+    /// IOWA aggregation of `values` ordered by the keys of `pairs`, once checked there are as
+    /// many as weights.
+    fn induced<K: PartialOrd, V>(
+        &self,
+        pairs: &[(K, V)],
+        values: &[f64],
+    ) -> Result<f64, AggregationError> {
+        for (index, (key, _)) in pairs.iter().enumerate() {
+            if pairs[..=index]
+                .iter()
+                .any(|(other, _)| key.partial_cmp(other).is_none())
+            {
+                return Err(AggregationError::IncomparableKey { index });
+            }
+        }
+        // Keys are totally ordered once checked
+        let cmp = |a: usize, b: usize| pairs[a].0.partial_cmp(&pairs[b].0).unwrap();
+        let mut order: Vec<usize> = (0..pairs.len()).collect();
+        order.sort_by(|a, b| cmp(*b, *a));
+
+        // Each group of tied keys shares the average of its values
+        let mut result = 0.0;
+        let mut start = 0;
+        while start < order.len() {
+            let end = start
+                + 1
+                + order[start + 1..]
+                    .iter()
+                    .take_while(|i| cmp(**i, order[start]) == Ordering::Equal)
+                    .count();
+            let mean =
+                order[start..end].iter().map(|i| values[*i]).sum::<f64>() / (end - start) as f64;
+            let weight: f64 = self.weights[start..end].iter().map(|w| *w as f64).sum();
+            result += weight * mean;
+            start = end;
+        }
+        Ok(result)
+    }
+
+    /// First of `values`, once checked there are as many as weights.
+    fn first<'a, V>(&self, values: &'a [V]) -> Result<&'a V, AggregationError> {
+        if values.len() != self.weights.len() {
+            return Err(AggregationError::LengthMismatch {
+                expected: self.weights.len(),
+                found: values.len(),
+            });
+        }
+        Ok(&values[0])
+    }
+}
+
+/// Normalized geometric sequence of `n` weights with ratio `e^log_ratio`.
+fn geometric_weights(n: usize, log_ratio: f64) -> Vec<f64> {
+    let exponents: Vec<f64> = (0..n).map(|i| i as f64 * log_ratio).collect();
+    let max = exponents.iter().copied().fold(f64::MIN, f64::max);
+    let weights: Vec<f64> = exponents.iter().map(|e| (e - max).exp()).collect();
+    let sum: f64 = weights.iter().sum();
+    weights.iter().map(|weight| weight / sum).collect()
+}
+
+/// Orness degree of `weights` (see [OwaWeights::orness]).
+fn orness_of(weights: &[f64]) -> f64 {
+    let n = weights.len();
+    if n == 1 {
+        return 0.5;
+    }
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| (n - i - 1) as f64 * weight)
+        .sum::<f64>()
+        / (n - 1) as f64
+}
@@ -0,0 +1,234 @@
+use crate::aggregation::{
+    granularity, saturated_delta, symbolic_values, AggregationError, Quantifier,
+};
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::LabelMembership;
+use crate::valuation::TwoTuple;
+
+/// Support functions, measuring how much a value is supported by another one given the distance
+/// between them.
+///
+/// Distances are normalized in range `[0, 1]` and supports should also lie in that range.
+#[derive(Debug, Clone, Copy)]
+pub enum Support {
+    /// `Sup(d) = 1 - d`.
+    Linear,
+    /// `Sup(d) = k · (1 - d)`, with `k` in range `[0, 1]`.
+    Scaled { k: f32 },
+    /// `Sup(d) = e^(-alpha · d²)`, with `alpha >= 0`.
+    Gaussian { alpha: f32 },
+    /// Custom function from distances to supports.
+    Custom(fn(f32) -> f32),
+}
+
+impl Support {
+    /// Support between two values at distance `distance`.
+    ///
+    /// # Arguments
+    /// * `distance`: Normalized distance, in range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Support;
+    /// assert_eq!(Support::Linear.support(0.25), 0.75);
+    /// assert_eq!(Support::Scaled { k: 0.5 }.support(0.5), 0.25);
+    /// assert_eq!(Support::Gaussian { alpha: 2.0 }.support(0.0), 1.0);
+    /// assert_eq!(Support::Custom(|d| 1.0 - d * d).support(0.5), 0.75);
+    /// ```
+    pub fn support(&self, distance: f32) -> f32 {
+        use Support::*;
+        match self {
+            Linear => 1.0 - distance,
+            Scaled { k } => k * (1.0 - distance),
+            Gaussian { alpha } => (-alpha * distance * distance).exp(),
+            Custom(function) => function(distance),
+        }
+    }
+}
+
+/// 2-tuple power average, Δ(Σ w[i] · β[i]), with `w[i] = (1 + T(i)) / Σ (1 + T(j))`, where
+/// `T(i)` is the support of β[i] from every other valuation.
+///
+/// Distances between valuations are the distances between their symbolic values
+/// (Δ<sup>-1</sup>) normalized by domain granularity, so valuations far from the rest (outliers)
+/// get lower weights.
+///
+/// # Arguments
+/// * `valuations`: Valuations over the same domain.
+/// * `support`: Support function.
+///
+/// Returns aggregated valuation and the weight computed for each valuation.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::{power_average, Support};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::utilities;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, -0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 4, 0.0).unwrap(),
+/// ];
+///
+/// let (result, weights) = power_average(&valuations, Support::Linear).unwrap();
+/// assert_eq!(result.to_string(), "(c, 0.40)");
+/// assert_eq!(
+///     weights.iter().map(|w| utilities::math::round_f32(*w, 3)).collect::<Vec<_>>(),
+///     vec![0.27, 0.27, 0.262, 0.198]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidSupport**: If a support is outside the range `[0, 1]`.
+///
+/// ```
+/// # use assessment::aggregation::{power_average, AggregationError, Support};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+/// ];
+///
+/// assert_eq!(
+///     power_average(&valuations, Support::Scaled { k: 2.0 }).map(|_| ()),
+///     Err(AggregationError::InvalidSupport { distance: 0.0, support: 2.0 })
+/// );
+/// ```
+pub fn power_average<'domain, T, H>(
+    valuations: &[TwoTuple<'domain, T, H>],
+    support: Support,
+) -> Result<(TwoTuple<'domain, T, H>, Vec<f32>), AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    let (first, values) = symbolic_values(valuations)?;
+    let supports = total_supports(first, &values, support)?;
+    let total: f64 = supports.iter().sum();
+    let weights: Vec<f64> = supports.iter().map(|support| support / total).collect();
+    let beta = weights.iter().zip(&values).map(|(w, v)| w * v).sum();
+    Ok((
+        saturated_delta(first, beta),
+        weights.iter().map(|weight| *weight as f32).collect(),
+    ))
+}
+
+/// 2-tuple power OWA, Δ(Σ w[i] · β[i]), with
+/// `w[i] = Q(R(i) / R) - Q((R(i) - 1 - T(i)) / R)`, where `T(i)` is the support of β[i] from
+/// every other valuation, `R(i)` the sum of `1 + T(j)` for valuations with β[j] greater than or
+/// equal to β[i] (in descending order) and `R` the sum of all of them.
+///
+/// See [power_average] for distances between valuations.
+///
+/// # Arguments
+/// * `valuations`: Valuations over the same domain.
+/// * `quantifier`: Linguistic quantifier generating weights.
+/// * `support`: Support function.
+///
+/// Returns aggregated valuation and the weight computed for each valuation (in the same order
+/// as valuations, not in descending order).
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::aggregation::{power_owa, Quantifier, Support};
+/// # use assessment::valuation::TwoTuple;
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::utilities;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let valuations = [
+///     TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, 0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 2, -0.2).unwrap(),
+///     TwoTuple::new_by_label_index(&domain, 4, 0.0).unwrap(),
+/// ];
+///
+/// let (result, weights) = power_owa(&valuations, Quantifier::Most, Support::Linear).unwrap();
+/// assert_eq!(result.to_string(), "(c, 0.04)");
+/// assert_eq!(
+///     weights.iter().map(|w| utilities::math::round_f32(*w, 3)).collect::<Vec<_>>(),
+///     vec![0.54, 0.337, 0.124, 0.0]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// **AggregationError::Empty**: If there are no valuations.
+///
+/// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+///
+/// **AggregationError::InvalidQuantifier**: If quantifier parameters don't satisfy
+/// `0 <= a < b <= 1`.
+///
+/// **AggregationError::InvalidSupport**: If a support is outside the range `[0, 1]`.
+pub fn power_owa<'domain, T, H>(
+    valuations: &[TwoTuple<'domain, T, H>],
+    quantifier: Quantifier,
+    support: Support,
+) -> Result<(TwoTuple<'domain, T, H>, Vec<f32>), AggregationError>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>>,
+{
+    quantifier.check()?;
+    let (first, values) = symbolic_values(valuations)?;
+    let supports = total_supports(first, &values, support)?;
+    let total: f64 = supports.iter().sum();
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*b].total_cmp(&values[*a]));
+
+    let proportion = |r: f64| quantifier.membership((r / total) as f32) as f64;
+    let mut weights = vec![0.0; values.len()];
+    let mut accumulated = 0.0;
+    for i in order {
+        weights[i] = proportion(accumulated + supports[i]) - proportion(accumulated);
+        accumulated += supports[i];
+    }
+    let beta = weights.iter().zip(&values).map(|(w, v)| w * v).sum();
+    Ok((
+        saturated_delta(first, beta),
+        weights.iter().map(|weight| *weight as f32).collect(),
+    ))
+}
+
+/// `1 + T(i)` for each of `values`, where `T(i)` is the support of `values[i]` from every other
+/// value.
+fn total_supports<T, H>(
+    valuation: &TwoTuple<T, H>,
+    values: &[f64],
+    support: Support,
+) -> Result<Vec<f64>, AggregationError>
+where
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>>,
+{
+    let g = granularity(valuation);
+    let mut supports = vec![1.0; values.len()];
+    for i in 0..values.len() {
+        for j in (i + 1)..values.len() {
+            let distance = ((values[i] - values[j]).abs() / g) as f32;
+            let value = support.support(distance);
+            if !(0.0..=1.0).contains(&value) {
+                return Err(AggregationError::InvalidSupport {
+                    distance,
+                    support: value,
+                });
+            }
+            supports[i] += value as f64;
+            supports[j] += value as f64;
+        }
+    }
+    Ok(supports)
+}
@@ -0,0 +1,310 @@
+use crate::aggregation::{
+    check_weights, granularity, saturated_delta, symbolic_values, AggregationError,
+};
+use crate::domain::{DomainHandle, Qualitative, Quantitative};
+use crate::fuzzy::LabelMembership;
+use crate::valuation::{Interval, Numeric, TwoTuple};
+
+/// Generators of quasi-arithmetic means, `φ⁻¹(Σ w[i] · φ(x[i]))`.
+///
+/// Generators should be continuous and strictly monotonic over the range `[0, 1]`, where
+/// normalized values lie.
+#[derive(Debug, Clone, Copy)]
+pub enum Generator {
+    /// Geometric mean, `φ(x) = ln(x)`.
+    Geometric,
+    /// Harmonic mean, `φ(x) = 1 / x`.
+    Harmonic,
+    /// Power (generalized) mean, `φ(x) = x^lambda`, with `lambda != 0`. It leads to the arithmetic
+    /// mean with `lambda == 1` and to the quadratic mean with `lambda == 2`.
+    Power { lambda: f32 },
+    /// Custom generator, given `φ` and its inverse.
+    Custom {
+        function: fn(f64) -> f64,
+        inverse: fn(f64) -> f64,
+    },
+}
+
+impl Generator {
+    /// Generator value, `φ(x)`.
+    ///
+    /// # Arguments
+    /// * `x`: Value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Generator;
+    /// assert_eq!(Generator::Geometric.apply(1.0), 0.0);
+    /// assert_eq!(Generator::Harmonic.apply(0.5), 2.0);
+    /// assert_eq!(Generator::Power { lambda: 2.0 }.apply(0.5), 0.25);
+    /// ```
+    pub fn apply(&self, x: f64) -> f64 {
+        use Generator::*;
+        match self {
+            Geometric => x.ln(),
+            Harmonic => 1.0 / x,
+            Power { lambda } => x.powf(*lambda as f64),
+            Custom { function, .. } => function(x),
+        }
+    }
+
+    /// Generator inverse value, `φ⁻¹(y)`.
+    ///
+    /// # Arguments
+    /// * `y`: Generator value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Generator;
+    /// assert_eq!(Generator::Geometric.inverse(0.0), 1.0);
+    /// assert_eq!(Generator::Harmonic.inverse(2.0), 0.5);
+    /// assert_eq!(Generator::Power { lambda: 2.0 }.inverse(0.25), 0.5);
+    /// ```
+    pub fn inverse(&self, y: f64) -> f64 {
+        use Generator::*;
+        match self {
+            Geometric => y.exp(),
+            Harmonic => 1.0 / y,
+            Power { lambda } => y.powf(1.0 / *lambda as f64),
+            Custom { inverse, .. } => inverse(y),
+        }
+    }
+
+    /// Weighted quasi-arithmetic mean, `φ⁻¹(Σ w[i] · φ(x[i]))`.
+    ///
+    /// # Arguments
+    /// * `values`: Pairs of value and weight, with non negative weights summing up to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Generator;
+    /// # use assessment::utilities;
+    /// let values = [(0.2, 0.5), (0.8, 0.3), (0.5, 0.2)];
+    ///
+    /// for (generator, expected) in [
+    ///     (Generator::Geometric, 0.3641),
+    ///     (Generator::Harmonic, 0.3053),
+    ///     (Generator::Power { lambda: 1.0 }, 0.44),
+    ///     (Generator::Power { lambda: 2.0 }, 0.5119),
+    ///     (Generator::Custom { function: |x| x.exp(), inverse: |y| y.ln() }, 0.4751),
+    /// ] {
+    ///     let mean = generator.mean(&values).unwrap();
+    ///     assert!(utilities::math::approx_equal_f64(mean, expected, 4));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If there are no values.
+    ///
+    /// **AggregationError::InvalidWeight**: If a weight is negative or non finite.
+    ///
+    /// **AggregationError::InvalidWeightsSum**: If weights don't sum up to 1.
+    ///
+    /// **AggregationError::InvalidPower**: If the power of a power mean is 0 or non finite.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, Generator};
+    /// assert_eq!(
+    ///     Generator::Power { lambda: 0.0 }.mean(&[(0.5, 1.0)]),
+    ///     Err(AggregationError::InvalidPower { lambda: 0.0 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::UndefinedMean**: If the mean is undefined (or non finite) for the
+    /// given values.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, Generator};
+    /// assert_eq!(
+    ///     Generator::Geometric.mean(&[(-0.5, 0.5), (0.5, 0.5)]),
+    ///     Err(AggregationError::UndefinedMean)
+    /// );
+    /// ```
+    pub fn mean(&self, values: &[(f64, f32)]) -> Result<f64, AggregationError> {
+        if values.is_empty() {
+            return Err(AggregationError::Empty);
+        }
+        check_weights(values.iter().map(|(_, weight)| *weight))?;
+        self.weighted(values.iter().map(|(value, weight)| (*value, *weight)))
+    }
+
+    /// Weighted quasi-arithmetic mean of numeric valuations normalized in domain 0.0 to 1.0
+    /// (see [Numeric::normalize]).
+    ///
+    /// Valuations may be defined over different domains. The result is expressed over the domain
+    /// of the first valuation.
+    ///
+    /// # Arguments
+    /// * `valuations`: Pairs of valuation and weight, with non negative weights summing up to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Generator;
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let other = Quantitative::new(0.0, 100.0).unwrap();
+    /// let valuations = [
+    ///     (Numeric::new(&domain, 2.0).unwrap(), 0.5),
+    ///     (Numeric::new(&other, 80.0).unwrap(), 0.3),
+    ///     (Numeric::new(&domain, 5.0).unwrap(), 0.2),
+    /// ];
+    ///
+    /// let result = Generator::Geometric.mean_numeric(&valuations).unwrap();
+    /// assert!(utilities::math::approx_equal_f64(result.value(), 3.641, 3));
+    /// assert_eq!(*result.domain(), domain);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [Generator::mean].
+    pub fn mean_numeric<'domain, H>(
+        &self,
+        valuations: &[(Numeric<'domain, f64, H>, f32)],
+    ) -> Result<Numeric<'domain, f64, H>, AggregationError>
+    where
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        let (first, _) = valuations.first().ok_or(AggregationError::Empty)?;
+        check_weights(valuations.iter().map(|(_, weight)| *weight))?;
+        let value = self.weighted(
+            valuations
+                .iter()
+                .map(|(valuation, weight)| (valuation.normalize().value(), *weight)),
+        )?;
+        Ok(first.denormalize(value))
+    }
+
+    /// Weighted quasi-arithmetic mean of interval valuations normalized in domain 0.0 to 1.0
+    /// (see [Interval::normalize]), aggregating lower and upper limits separately.
+    ///
+    /// Valuations may be defined over different domains. The result is expressed over the domain
+    /// of the first valuation.
+    ///
+    /// # Arguments
+    /// * `valuations`: Pairs of valuation and weight, with non negative weights summing up to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Generator;
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::valuation::Interval;
+    /// # use assessment::utilities;
+    /// let domain = Quantitative::new(0.0, 10.0).unwrap();
+    /// let valuations = [
+    ///     (Interval::new(&domain, 2.0, 4.0).unwrap(), 0.5),
+    ///     (Interval::new(&domain, 8.0, 8.0).unwrap(), 0.5),
+    /// ];
+    ///
+    /// let (min, max) = Generator::Geometric.mean_interval(&valuations).unwrap().value();
+    /// assert!(utilities::math::approx_equal_f64(min, 4.0, 4));
+    /// assert!(utilities::math::approx_equal_f64(max, 5.6569, 4));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [Generator::mean].
+    pub fn mean_interval<'domain, H>(
+        &self,
+        valuations: &[(Interval<'domain, f64, H>, f32)],
+    ) -> Result<Interval<'domain, f64, H>, AggregationError>
+    where
+        H: DomainHandle<Quantitative<f64>>,
+    {
+        let (first, _) = valuations.first().ok_or(AggregationError::Empty)?;
+        check_weights(valuations.iter().map(|(_, weight)| *weight))?;
+        let limits: Vec<((f64, f64), f32)> = valuations
+            .iter()
+            .map(|(valuation, weight)| (valuation.normalize().value(), *weight))
+            .collect();
+        let min = self.weighted(limits.iter().map(|((min, _), weight)| (*min, *weight)))?;
+        let max = self.weighted(limits.iter().map(|((_, max), weight)| (*max, *weight)))?;
+        Ok(first.denormalize(min, max))
+    }
+
+    /// Weighted quasi-arithmetic mean of 2-tuple valuations, Δ(g · φ⁻¹(Σ w[i] · φ(β[i] / g))),
+    /// with β[i] = Δ<sup>-1</sup>(x<sub>i</sub>) and `g` the domain granularity.
+    ///
+    /// # Arguments
+    /// * `valuations`: Pairs of valuation and weight, with valuations over the same domain and
+    ///   non negative weights summing up to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::Generator;
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuations = [
+    ///     (TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(), 0.5),
+    ///     (TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap(), 0.3),
+    ///     (TwoTuple::new_by_label_index(&domain, 2, 0.1).unwrap(), 0.2),
+    /// ];
+    ///
+    /// for (generator, expected) in [
+    ///     (Generator::Geometric, "(c, -0.31)"),
+    ///     (Generator::Harmonic, "(c, -0.41)"),
+    ///     (Generator::Power { lambda: 1.0 }, "(c, -0.20)"),
+    /// ] {
+    ///     assert_eq!(generator.mean_two_tuple(&valuations).unwrap().to_string(), expected);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// See [Generator::mean] for the rest of them.
+    pub fn mean_two_tuple<'domain, T, H>(
+        &self,
+        valuations: &[(TwoTuple<'domain, T, H>, f32)],
+    ) -> Result<TwoTuple<'domain, T, H>, AggregationError>
+    where
+        T: LabelMembership + PartialEq,
+        H: DomainHandle<Qualitative<T>>,
+    {
+        let (first, values) = symbolic_values(valuations.iter().map(|(valuation, _)| valuation))?;
+        check_weights(valuations.iter().map(|(_, weight)| *weight))?;
+        let g = granularity(first);
+        let value = self.weighted(
+            values
+                .iter()
+                .zip(valuations)
+                .map(|(value, (_, weight))| (value / g, *weight)),
+        )?;
+        Ok(saturated_delta(first, value * g))
+    }
+
+    /// `φ⁻¹(Σ w[i] · φ(x[i]))` given pairs of value and (already checked) weight.
+    ///
+    /// Values with null weights are ignored, so they don't produce undefined results.
+    pub(crate) fn weighted(
+        &self,
+        values: impl IntoIterator<Item = (f64, f32)>,
+    ) -> Result<f64, AggregationError> {
+        if let Generator::Power { lambda } = self {
+            if *lambda == 0.0 || !lambda.is_finite() {
+                return Err(AggregationError::InvalidPower { lambda: *lambda });
+            }
+        }
+        let sum: f64 = values
+            .into_iter()
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(value, weight)| weight as f64 * self.apply(value))
+            .sum();
+        let mean = self.inverse(sum);
+        if mean.is_nan() || mean.is_infinite() {
+            Err(AggregationError::UndefinedMean)
+        } else {
+            Ok(mean)
+        }
+    }
+}
@@ -0,0 +1,477 @@
+use crate::aggregation::fuzzy_measure::{mask, members, subsets};
+use crate::aggregation::AggregationError;
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::LabelMembership;
+use crate::valuation::{Linguistic, Single};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Qualitative capacity over a set of `n` criteria, whose measures are labels of a qualitative
+/// domain.
+///
+/// Subsets of criteria are given as lists of criterion indexes (see [FuzzyMeasure]).
+///
+/// The domain is borrowed by default. See [SharedQualitativeCapacity] for capacities owning
+/// their domain.
+///
+/// [FuzzyMeasure]: crate::aggregation::FuzzyMeasure
+#[derive(Debug, PartialEq)]
+pub struct QualitativeCapacity<
+    'domain,
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>> = &'domain Qualitative<T>,
+> {
+    domain: H,
+    criteria: usize,
+    indexes: Vec<usize>,
+    lifetime: PhantomData<&'domain Qualitative<T>>,
+}
+
+/// Qualitative capacity sharing the ownership of its domain.
+pub type SharedQualitativeCapacity<T> = QualitativeCapacity<'static, T, Arc<Qualitative<T>>>;
+
+impl<'domain, T: LabelMembership> QualitativeCapacity<'domain, T> {
+    /// Creates a new capacity given the label measuring every subset of criteria.
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain reference.
+    /// * `criteria`: Number of criteria.
+    /// * `indexes`: Label index measuring each subset, indexed by its bitmask (the subset with
+    ///   criteria `0` and `2` is at position `0b101`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 2, vec![0, 1, 0, 2]).unwrap();
+    ///
+    /// assert_eq!(capacity.value(&[0]).unwrap().to_string(), "medium");
+    /// assert_eq!(capacity.value(&[0, 1]).unwrap().to_string(), "high");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::TooManyCriteria**: If `criteria` is greater than
+    /// [MAX_CRITERIA](crate::aggregation::fuzzy_measure::MAX_CRITERIA).
+    ///
+    /// **AggregationError::LengthMismatch**: If there aren't `2^criteria` labels.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// assert_eq!(
+    ///     QualitativeCapacity::new(&domain, 2, vec![0, 2]),
+    ///     Err(AggregationError::LengthMismatch { expected: 4, found: 2 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::InvalidCapacityLabel**: If a label index is out of domain range.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// assert_eq!(
+    ///     QualitativeCapacity::new(&domain, 2, vec![0, 1, 3, 2]),
+    ///     Err(AggregationError::InvalidCapacityLabel { subset: vec![1], index: 3 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::InvalidCapacityBoundaries**: If the empty set isn't measured by the
+    /// first label or all criteria by the last one.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// assert_eq!(
+    ///     QualitativeCapacity::new(&domain, 2, vec![0, 1, 0, 1]),
+    ///     Err(AggregationError::InvalidCapacityBoundaries { empty: 0, full: 1 })
+    /// );
+    /// ```
+    ///
+    /// **AggregationError::NonMonotonicMeasure**: If a subset is measured by a greater label than
+    /// one of its supersets.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// assert_eq!(
+    ///     QualitativeCapacity::new(&domain, 3, vec![0, 2, 1, 2, 0, 1, 1, 2]),
+    ///     Err(AggregationError::NonMonotonicMeasure { subset: vec![0], superset: vec![0, 2] })
+    /// );
+    /// ```
+    pub fn new(
+        domain: &'domain Qualitative<T>,
+        criteria: usize,
+        indexes: Vec<usize>,
+    ) -> Result<Self, AggregationError> {
+        Self::with_handle(domain, criteria, indexes)
+    }
+
+    /// Returns capacity domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 1, vec![0, 2]).unwrap();
+    /// assert_eq!(*capacity.domain(), domain);
+    /// ```
+    pub fn domain(&self) -> &'domain Qualitative<T> {
+        self.domain
+    }
+}
+
+impl<T: LabelMembership> SharedQualitativeCapacity<T> {
+    /// Creates a new capacity given the label measuring every subset of criteria, over a shared
+    /// `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `criteria`: Number of criteria.
+    /// * `indexes`: Label index measuring each subset (see [QualitativeCapacity::new]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::{QualitativeCapacity, SharedQualitativeCapacity};
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["low", "medium", "high"].unwrap());
+    /// let capacity: SharedQualitativeCapacity<_> =
+    ///     QualitativeCapacity::new_shared(&domain, 2, vec![0, 1, 0, 2]).unwrap();
+    /// let valuations = ["medium", "high"].map(|name| Single::new_shared_by_label_name(&domain, name).unwrap());
+    ///
+    /// let handle = std::thread::spawn(move || capacity.sugeno(&valuations).unwrap().to_string());
+    /// assert_eq!(handle.join().unwrap(), "medium");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [QualitativeCapacity::new].
+    pub fn new_shared(
+        domain: &Arc<Qualitative<T>>,
+        criteria: usize,
+        indexes: Vec<usize>,
+    ) -> Result<Self, AggregationError> {
+        Self::with_handle(Arc::clone(domain), criteria, indexes)
+    }
+
+    /// Returns capacity domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["low", "medium", "high"].unwrap());
+    /// let capacity = QualitativeCapacity::new_shared(&domain, 1, vec![0, 2]).unwrap();
+    /// assert_eq!(capacity.domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Qualitative<T> {
+        &self.domain
+    }
+}
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>>
+    QualitativeCapacity<'domain, T, H>
+{
+    /// Validated capacity over any domain handle (see [QualitativeCapacity::new]).
+    fn with_handle(
+        domain: H,
+        criteria: usize,
+        indexes: Vec<usize>,
+    ) -> Result<Self, AggregationError> {
+        let expected = subsets(criteria)?;
+        if indexes.len() != expected {
+            return Err(AggregationError::LengthMismatch {
+                expected,
+                found: indexes.len(),
+            });
+        }
+        if let Some(subset) = indexes
+            .iter()
+            .position(|index| *index >= domain.cardinality())
+        {
+            return Err(AggregationError::InvalidCapacityLabel {
+                subset: members(subset),
+                index: indexes[subset],
+            });
+        }
+        let (empty, full) = (indexes[0], indexes[expected - 1]);
+        if empty != 0 || full != domain.cardinality() - 1 {
+            return Err(AggregationError::InvalidCapacityBoundaries { empty, full });
+        }
+        for subset in 0..expected {
+            for criterion in 0..criteria {
+                let superset = subset | (1 << criterion);
+                if indexes[superset] < indexes[subset] {
+                    return Err(AggregationError::NonMonotonicMeasure {
+                        subset: members(subset),
+                        superset: members(superset),
+                    });
+                }
+            }
+        }
+        Ok(Self {
+            domain,
+            criteria,
+            indexes,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Returns the number of criteria.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// assert_eq!(QualitativeCapacity::new(&domain, 1, vec![0, 2]).unwrap().criteria(), 1);
+    /// ```
+    pub fn criteria(&self) -> usize {
+        self.criteria
+    }
+
+    /// Label measuring a subset of criteria.
+    ///
+    /// # Arguments
+    /// * `subset`: Criterion indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 2, vec![0, 1, 0, 2]).unwrap();
+    /// assert_eq!(capacity.value(&[]).unwrap().to_string(), "low");
+    /// assert_eq!(capacity.value(&[1]).unwrap().to_string(), "low");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::InvalidSubset**: If `subset` has repeated criteria or criteria out of
+    /// range.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 2, vec![0, 1, 0, 2]).unwrap();
+    /// assert_eq!(capacity.value(&[2]), Err(AggregationError::InvalidSubset { subset: vec![2] }));
+    /// ```
+    pub fn value(&self, subset: &[usize]) -> Result<Single<'domain, T, H>, AggregationError> {
+        let index = self.indexes[mask(self.criteria, subset)?];
+        Ok(Single::label_in(self.domain.clone(), index))
+    }
+
+    /// Sugeno integral of `valuations`, `max(min(a_(i), μ(A_(i))))`, where `a_(i)` are
+    /// valuations sorted in ascending order and `A_(i)` the criteria with valuations `>= a_(i)`.
+    ///
+    /// Only label orders are used, so valuations must be defined over the capacity domain. See
+    /// [QualitativeCapacity::sugeno_mapped] for valuations over a different domain.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuation of each criterion, over the capacity domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 2, vec![0, 1, 0, 2]).unwrap();
+    ///
+    /// for (names, expected) in [
+    ///     (["medium", "high"], "medium"),
+    ///     (["high", "medium"], "medium"),
+    ///     (["high", "high"], "high"),
+    /// ] {
+    ///     let valuations = names.map(|name| Single::new_by_label_name(&domain, name).unwrap());
+    ///     assert_eq!(capacity.sugeno(&valuations).unwrap().to_string(), expected);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If there are no valuations.
+    ///
+    /// **AggregationError::LengthMismatch**: If there isn't a valuation for each criterion.
+    ///
+    /// **AggregationError::DomainMismatch**: If a valuation isn't defined over the capacity
+    /// domain.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 2, vec![0, 1, 0, 2]).unwrap();
+    /// let valuations = [
+    ///     Single::new_by_label_index(&domain, 1).unwrap(),
+    ///     Single::new_by_label_index(&other, 1).unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(capacity.sugeno(&valuations), Err(AggregationError::DomainMismatch { index: 1 }));
+    /// ```
+    pub fn sugeno(
+        &self,
+        valuations: &[Single<'domain, T, H>],
+    ) -> Result<Single<'domain, T, H>, AggregationError>
+    where
+        T: PartialEq,
+    {
+        let reference = Single::label_in(self.domain.clone(), 0);
+        for (index, valuation) in valuations.iter().enumerate() {
+            if reference.check_same_domain(valuation).is_err() {
+                return Err(AggregationError::DomainMismatch { index });
+            }
+        }
+        self.integral(valuations, |index| index)
+    }
+
+    /// Sugeno integral of `valuations` defined over a different domain than the capacity one
+    /// (see [QualitativeCapacity::sugeno]).
+    ///
+    /// Capacity labels are translated into valuation labels through `map`, an explicit order
+    /// preserving map between both scales, so only label orders are used.
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuation of each criterion, over the same domain.
+    /// * `map`: Valuations label index for each capacity label index, non decreasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::aggregation::QualitativeCapacity;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&importance, 2, vec![0, 1, 0, 2]).unwrap();
+    ///
+    /// for (names, expected) in [(["b", "e"], "b"), (["d", "c"], "c"), (["e", "e"], "e")] {
+    ///     let valuations = names.map(|name| Single::new_by_label_name(&domain, name).unwrap());
+    ///     assert_eq!(capacity.sugeno_mapped(&valuations, &[0, 2, 4]).unwrap().to_string(), expected);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **AggregationError::Empty**: If there are no valuations.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::fuzzy::membership::Trapezoidal;
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&domain, 1, vec![0, 2]).unwrap();
+    /// let valuations: [Single<Trapezoidal>; 0] = [];
+    ///
+    /// assert_eq!(capacity.sugeno_mapped(&valuations, &[0, 1, 2]), Err(AggregationError::Empty));
+    /// ```
+    ///
+    /// **AggregationError::LengthMismatch**: If there isn't a valuation for each criterion or a
+    /// valuations label for each capacity label.
+    ///
+    /// **AggregationError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// **AggregationError::InvalidScaleMap**: If a capacity label is mapped out of the valuations
+    /// domain range or below the previous one.
+    ///
+    /// ```
+    /// # use assessment::aggregation::{AggregationError, QualitativeCapacity};
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let importance = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    /// let capacity = QualitativeCapacity::new(&importance, 1, vec![0, 2]).unwrap();
+    /// let valuations = [Single::new_by_label_index(&domain, 1).unwrap()];
+    ///
+    /// assert_eq!(
+    ///     capacity.sugeno_mapped(&valuations, &[0, 3, 2]),
+    ///     Err(AggregationError::InvalidScaleMap { index: 2, label: 2 })
+    /// );
+    /// assert_eq!(
+    ///     capacity.sugeno_mapped(&valuations, &[0, 2, 5]),
+    ///     Err(AggregationError::InvalidScaleMap { index: 2, label: 5 })
+    /// );
+    /// ```
+    pub fn sugeno_mapped<'v, U, V>(
+        &self,
+        valuations: &[Single<'v, U, V>],
+        map: &[usize],
+    ) -> Result<Single<'v, U, V>, AggregationError>
+    where
+        U: LabelMembership + PartialEq,
+        V: DomainHandle<Qualitative<U>>,
+    {
+        let first = valuations.first().ok_or(AggregationError::Empty)?;
+        if map.len() != self.domain.cardinality() {
+            return Err(AggregationError::LengthMismatch {
+                expected: self.domain.cardinality(),
+                found: map.len(),
+            });
+        }
+        let mut previous = 0;
+        for (index, label) in map.iter().enumerate() {
+            if *label < previous || *label >= first.cardinality() {
+                return Err(AggregationError::InvalidScaleMap {
+                    index,
+                    label: *label,
+                });
+            }
+            previous = *label;
+        }
+        for (index, valuation) in valuations.iter().enumerate() {
+            if first.check_same_domain(valuation).is_err() {
+                return Err(AggregationError::DomainMismatch { index });
+            }
+        }
+        self.integral(valuations, |index| map[index])
+    }
+
+    /// Sugeno integral of `valuations`, with capacity labels translated by `map`.
+    fn integral<'v, U, V>(
+        &self,
+        valuations: &[Single<'v, U, V>],
+        map: impl Fn(usize) -> usize,
+    ) -> Result<Single<'v, U, V>, AggregationError>
+    where
+        U: LabelMembership,
+        V: DomainHandle<Qualitative<U>>,
+    {
+        let first = valuations.first().ok_or(AggregationError::Empty)?;
+        if valuations.len() != self.criteria {
+            return Err(AggregationError::LengthMismatch {
+                expected: self.criteria,
+                found: valuations.len(),
+            });
+        }
+        let mut order: Vec<usize> = (0..self.criteria).collect();
+        order.sort_by_key(|criterion| valuations[*criterion].index());
+        let mut subset = self.indexes.len() - 1;
+        let mut result = 0;
+        for criterion in order {
+            let measure = map(self.indexes[subset]);
+            result = result.max(valuations[criterion].index().min(measure));
+            subset &= !(1 << criterion);
+        }
+        Ok(first.with_index(result))
+    }
+}
@@ -0,0 +1,295 @@
+use crate::domain::factories::symmetric_domain;
+use crate::domain::Qualitative;
+use crate::fuzzy::membership::Trapezoidal;
+use std::fmt::{Display, Formatter};
+
+/// Reasons why a domain can't be exactly represented in a BLTS.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BltsIncompatibility {
+    /// Domain without labels.
+    Empty,
+    /// Some label isn't triangular.
+    NonTriangular,
+    /// Labels aren't a fuzzy (Ruspini) partition.
+    NonFuzzyPartition,
+    /// Labels aren't symmetrical.
+    NonSymmetrical,
+    /// Labels aren't uniformly distributed.
+    NonUniform,
+}
+
+impl Display for BltsIncompatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use BltsIncompatibility::*;
+        match &self {
+            Empty => write!(f, "Domain has no labels."),
+            NonTriangular => write!(f, "Domain labels are not triangular."),
+            NonFuzzyPartition => write!(f, "Domain labels are not a fuzzy partition."),
+            NonSymmetrical => write!(f, "Domain labels are not symmetrical."),
+            NonUniform => write!(f, "Domain labels are not uniformly distributed."),
+        }
+    }
+}
+
+/// BLTS selection errors types.
+#[derive(Debug, PartialEq)]
+pub enum BltsError {
+    /// No domain can be exactly represented in a BLTS.
+    NoCompatibleDomain,
+    /// Even the smallest BLTS for compatible domains has more labels than allowed.
+    MaxCardinalityExceeded {
+        cardinality: usize,
+        max_cardinality: usize,
+    },
+    /// BLTS that can't be built, with the domain error message.
+    InvalidBlts { reason: String },
+}
+
+impl Display for BltsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use BltsError::*;
+        match &self {
+            NoCompatibleDomain => {
+                write!(f, "No domain can be exactly represented in a BLTS.")
+            }
+            MaxCardinalityExceeded {
+                cardinality,
+                max_cardinality,
+            } => {
+                write!(
+                    f,
+                    "BLTS needs at least {} labels, more than the maximum ({}).",
+                    cardinality, max_cardinality
+                )
+            }
+            InvalidBlts { reason } => {
+                write!(f, "BLTS can't be built. {}", reason)
+            }
+        }
+    }
+}
+
+/// BLTS selected for the domains of a problem.
+#[derive(Debug, PartialEq)]
+pub struct BltsSelection {
+    domain: Qualitative<Trapezoidal>,
+    exact: bool,
+    incompatible: Vec<(usize, Vec<BltsIncompatibility>)>,
+}
+
+impl BltsSelection {
+    /// Selects and builds the BLTS for a set of domains.
+    ///
+    /// Labels of a domain are exactly represented in a BLTS when they are a uniform and
+    /// symmetrical fuzzy partition of triangular labels (BLTS themselves, save maybe for their
+    /// number of labels) and the BLTS granularity is a multiple of theirs. So the selected
+    /// granularity is the least common multiple of compatible domains granularities (doubled if
+    /// odd, as BLTS have an odd number of labels). When it leads to more than `max_cardinality`
+    /// labels (or overflows), the largest even granularity within that bound which is a multiple
+    /// of the granularities of as many compatible domains as possible is selected instead, and
+    /// transformations from the remaining ones won't be exact. It isn't lower than the maximum
+    /// granularity of compatible domains (also doubled if odd).
+    ///
+    /// The BLTS is generated as qualitative_symmetric_domain macro does, with labels named
+    /// `s0`, `s1`, ...
+    ///
+    /// # Arguments
+    /// * `domains`: Domains used in the problem.
+    /// * `max_cardinality`: Maximum number of labels of the BLTS, which bounds the size of the
+    ///   least common multiple BLTS.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::{BltsIncompatibility, BltsSelection};
+    /// # use assessment::{qualitative_domain, qualitative_symmetric_domain};
+    /// let three = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let four = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    /// let five = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let other = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 0.25],
+    ///     "b" => vec![0.0, 0.25, 1.0],
+    ///     "c" => vec![0.25, 1.0, 1.0]
+    /// ]
+    /// .unwrap();
+    ///
+    /// let selection = BltsSelection::new(&[&three, &four, &five, &other], 15).unwrap();
+    /// assert_eq!(selection.domain().cardinality(), 13);
+    /// assert!(selection.domain().is_blts());
+    /// assert!(selection.is_exact());
+    /// assert_eq!(
+    ///     selection.incompatible(),
+    ///     &[(3, vec![BltsIncompatibility::NonSymmetrical, BltsIncompatibility::NonUniform])]
+    /// );
+    ///
+    /// // 9 labels are exact for both three and five labels domains
+    /// let selection = BltsSelection::new(&[&three, &four, &five], 9).unwrap();
+    /// assert_eq!(selection.domain().cardinality(), 9);
+    /// assert!(!selection.is_exact());
+    ///
+    /// // 7 labels are exact for the three labels domain
+    /// let selection = BltsSelection::new(&[&three, &four, &five], 8).unwrap();
+    /// assert_eq!(selection.domain().cardinality(), 7);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **BltsError::NoCompatibleDomain**: If no domain is compatible with a BLTS.
+    ///
+    /// ```
+    /// # use assessment::domain::{BltsError, BltsSelection};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a"].unwrap();
+    /// assert_eq!(BltsSelection::new(&[&domain], 9), Err(BltsError::NoCompatibleDomain));
+    /// ```
+    ///
+    /// **BltsError::MaxCardinalityExceeded**: If even the BLTS for the maximum granularity of
+    /// compatible domains (doubled if odd) has more than `max_cardinality` labels.
+    ///
+    /// ```
+    /// # use assessment::domain::{BltsError, BltsSelection};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let three = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let four = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    /// assert_eq!(
+    ///     BltsSelection::new(&[&three, &four], 5),
+    ///     Err(BltsError::MaxCardinalityExceeded { cardinality: 7, max_cardinality: 5 })
+    /// );
+    /// ```
+    ///
+    /// **BltsError::InvalidBlts**: If the BLTS can't be built (see [symmetric_domain]).
+    pub fn new(
+        domains: &[&Qualitative<Trapezoidal>],
+        max_cardinality: usize,
+    ) -> Result<Self, BltsError> {
+        let mut granularities = Vec::new();
+        let mut incompatible = Vec::new();
+        for (index, domain) in domains.iter().enumerate() {
+            let reasons = incompatibilities(domain);
+            if reasons.is_empty() {
+                granularities.push(domain.cardinality() - 1);
+            } else {
+                incompatible.push((index, reasons));
+            }
+        }
+        if granularities.is_empty() {
+            return Err(BltsError::NoCompatibleDomain);
+        }
+
+        let even = |g: usize| {
+            if g.is_multiple_of(2) {
+                Some(g)
+            } else {
+                g.checked_mul(2)
+            }
+        };
+        let bound = max_cardinality.saturating_sub(1);
+        let granularity = match granularities
+            .iter()
+            .copied()
+            .try_fold(1, lcm)
+            .and_then(even)
+        {
+            Some(granularity) if granularity <= bound => granularity,
+            _ => {
+                let max = granularities.iter().copied().max().unwrap();
+                let min = even(max).unwrap_or(usize::MAX);
+                if min > bound {
+                    return Err(BltsError::MaxCardinalityExceeded {
+                        cardinality: min.saturating_add(1),
+                        max_cardinality,
+                    });
+                }
+                // Granularities exact for a set of domains are the multiples of the least common
+                // multiple of theirs, so only the greatest multiple within bound of each one is
+                // a candidate
+                let mut multiples = vec![2];
+                for &g in &granularities {
+                    for index in 0..multiples.len() {
+                        if let Some(multiple) = lcm(multiples[index], g).filter(|m| *m <= bound) {
+                            if !multiples.contains(&multiple) {
+                                multiples.push(multiple);
+                            }
+                        }
+                    }
+                }
+                let exact = |granularity: usize| {
+                    granularities
+                        .iter()
+                        .filter(|g| granularity.is_multiple_of(**g))
+                        .count()
+                };
+                multiples
+                    .into_iter()
+                    .map(|multiple| bound / multiple * multiple)
+                    .filter(|granularity| *granularity >= min)
+                    .max_by_key(|granularity| (exact(*granularity), *granularity))
+                    .unwrap_or(min)
+            }
+        };
+        let names: Vec<String> = (0..=granularity).map(|i| format!("s{}", i)).collect();
+        Ok(Self {
+            domain: symmetric_domain(&names).map_err(|reason| BltsError::InvalidBlts { reason })?,
+            exact: granularities.iter().all(|g| granularity % g == 0),
+            incompatible,
+        })
+    }
+
+    /// Returns selected BLTS.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::BltsSelection;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let selection = BltsSelection::new(&[&domain], 9).unwrap();
+    /// assert_eq!(selection.domain().get_labels_names(), vec!["s0", "s1", "s2"]);
+    /// ```
+    pub fn domain(&self) -> &Qualitative<Trapezoidal> {
+        &self.domain
+    }
+
+    /// Checks if every compatible domain is exactly represented in the selected BLTS.
+    ///
+    /// See [BltsSelection::new].
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Returns the position of each domain not compatible with a BLTS, and the reasons why.
+    ///
+    /// See [BltsSelection::new].
+    pub fn incompatible(&self) -> &[(usize, Vec<BltsIncompatibility>)] {
+        &self.incompatible
+    }
+}
+
+/// Reasons why the labels of `domain` can't be exactly represented in a BLTS.
+fn incompatibilities(domain: &Qualitative<Trapezoidal>) -> Vec<BltsIncompatibility> {
+    use BltsIncompatibility::*;
+    if domain.cardinality() == 0 {
+        return vec![Empty];
+    }
+    [
+        (domain.is_triangular(), NonTriangular),
+        (domain.is_fuzzy_partition(), NonFuzzyPartition),
+        (domain.is_symmetrical(), NonSymmetrical),
+        (domain.is_uniform(), NonUniform),
+    ]
+    .into_iter()
+    .filter(|(valid, _)| !valid)
+    .map(|(_, reason)| reason)
+    .collect()
+}
+
+/// Least common multiple, if it doesn't overflow.
+fn lcm(a: usize, b: usize) -> Option<usize> {
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    (a / gcd(a, b)).checked_mul(b)
+}
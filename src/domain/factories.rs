@@ -1,3 +1,8 @@
+use crate::domain::Qualitative;
+use crate::fuzzy::membership::Trapezoidal;
+use crate::fuzzy::Label;
+use crate::utilities;
+
 /// Qualitative domain.
 ///
 /// Generates a qualitative domain. Note it is a wrapper of trapezoidal_labels macro.
@@ -78,7 +83,7 @@ macro_rules! qualitative_domain {
 
 /// Creates a symmetrical qualitative domain.
 ///
-/// Note it is a wrapper of qualitative_domain macro.
+/// Note it is a wrapper of [symmetric_domain] function.
 ///
 /// # Examples
 ///
@@ -115,32 +120,71 @@ macro_rules! qualitative_domain {
 ///
 /// # Errors
 ///
-/// See qualitative_domain macro.
+/// See [symmetric_domain].
 ///
 #[macro_export]
 macro_rules! qualitative_symmetric_domain {
     ( $( $name:expr ),* ) => {
-        {
-            let elements = $crate::count!($($name)*);
-            match elements {
-                0 => $crate::qualitative_domain![],
-                1 => $crate::qualitative_domain![$($name => vec![0., 0., 1., 1.]),*],
-                _ => {
-                    let denominator = (elements - 1) as f32;
-                    let mut values = vec![0.];
-                    (0..elements)
-                        .map(|i| $crate::utilities::math::round_f32((i as f32) / denominator, 5))
-                        .for_each(|v| values.push(v));
-                    values.push(1.);
+        $crate::domain::factories::symmetric_domain::<String>(&[$( $name.to_string() ),*])
+    }
+}
 
-                    let mut memberships = (0..elements)
-                        .map(|l| vec![values[l], values[l + 1], values[l + 2]])
-                        .rev()
-                        .collect::<Vec<Vec<f32>>>();
+/// Creates a symmetrical qualitative domain given its labels names.
+///
+/// Labels are uniformly distributed triangular labels, as generated by
+/// qualitative_symmetric_domain macro, which is a wrapper of this function.
+///
+/// # Arguments
+/// * `names`: Labels names.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::domain::factories::symmetric_domain;
+/// # use assessment::qualitative_symmetric_domain;
+/// let names: Vec<String> = (0..5).map(|i| format!("s{}", i)).collect();
+/// let domain = symmetric_domain(&names).unwrap();
+///
+/// assert_eq!(domain.get_labels_names(), vec!["s0", "s1", "s2", "s3", "s4"]);
+/// assert!(domain.is_blts());
+/// assert_eq!(
+///     domain.to_string(),
+///     qualitative_symmetric_domain!["s0", "s1", "s2", "s3", "s4"].unwrap().to_string()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// **String**: If any label name is invalid ([Label::new]) or labels are invalid (see
+/// [Qualitative::new]).
+///
+/// ```
+/// # use assessment::domain::factories::symmetric_domain;
+/// assert!(symmetric_domain(&["a", "a"]).is_err());
+/// ```
+pub fn symmetric_domain<S: ToString>(names: &[S]) -> Result<Qualitative<Trapezoidal>, String> {
+    let elements = names.len();
+    let memberships = match elements {
+        0 => vec![],
+        1 => vec![vec![0., 0., 1., 1.]],
+        _ => {
+            let denominator = (elements - 1) as f32;
+            let mut values = vec![0.];
+            (0..elements)
+                .map(|i| utilities::math::round_f32((i as f32) / denominator, 5))
+                .for_each(|v| values.push(v));
+            values.push(1.);
 
-                    $crate::qualitative_domain![$( $name => memberships.pop().unwrap() ),*]
-                }
-            }
+            (0..elements)
+                .map(|l| vec![values[l], values[l + 1], values[l + 2]])
+                .collect::<Vec<Vec<f32>>>()
         }
+    };
+
+    let mut labels = Vec::with_capacity(elements);
+    for (name, membership) in names.iter().zip(memberships) {
+        let membership = Trapezoidal::new(membership).map_err(|e| format!("{}", e))?;
+        labels.push(Label::new(name.to_string(), membership).map_err(|e| format!("{}", e))?);
     }
+    Qualitative::new(labels).map_err(|e| format!("{}", e))
 }
@@ -0,0 +1,252 @@
+use crate::domain::factories::symmetric_domain;
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::membership::Trapezoidal;
+use crate::valuation::TwoTuple;
+use std::fmt::{Display, Formatter};
+
+/// Linguistic hierarchy.
+///
+/// Sequence of symmetric and uniform levels, where level `t + 1` has `n(t + 1) = 2 · n(t) - 1`
+/// labels, so that it keeps the labels centers of level `t` and adds a new label between each
+/// two of them. Levels are numbered from `0`, the coarsest one, and are BLTS (odd number of
+/// labels) from level `1` on, or from level `0` if it has an odd number of labels.
+#[derive(Debug, PartialEq)]
+pub struct LinguisticHierarchy {
+    levels: Vec<Qualitative<Trapezoidal>>,
+}
+
+/// Linguistic hierarchy errors types.
+#[derive(Debug, PartialEq)]
+pub enum HierarchyError {
+    /// Initial cardinality lower than 2.
+    InvalidCardinality { cardinality: usize },
+    /// Hierarchy without levels.
+    NoLevels,
+    /// Level out of hierarchy range.
+    InvalidLevel { level: usize, levels: usize },
+    /// Valuation defined over a domain which isn't a hierarchy level.
+    UnknownDomain,
+}
+
+impl Display for HierarchyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use HierarchyError::*;
+        match &self {
+            InvalidCardinality { cardinality } => {
+                write!(
+                    f,
+                    "Invalid initial cardinality {}. Value should be greater than 1.",
+                    cardinality
+                )
+            }
+            NoLevels => write!(f, "Hierarchy should have at least one level."),
+            InvalidLevel { level, levels } => {
+                write!(
+                    f,
+                    "Invalid level {}. Value should be in range [0, {}).",
+                    level, levels
+                )
+            }
+            UnknownDomain => write!(f, "Valuation domain is not a hierarchy level."),
+        }
+    }
+}
+
+impl LinguisticHierarchy {
+    /// Creates a new hierarchy.
+    ///
+    /// Levels are generated as qualitative_symmetric_domain macro does, with labels named `s0`,
+    /// `s1`, ...
+    ///
+    /// # Arguments
+    /// * `cardinality`: Number of labels of the first level.
+    /// * `levels`: Number of levels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::LinguisticHierarchy;
+    /// let hierarchy = LinguisticHierarchy::new(3, 4).unwrap();
+    /// assert_eq!(
+    ///     hierarchy.levels().iter().map(|level| level.cardinality()).collect::<Vec<_>>(),
+    ///     vec![3, 5, 9, 17]
+    /// );
+    /// assert!(hierarchy.levels().iter().all(|level| level.is_blts()));
+    ///
+    /// let hierarchy = LinguisticHierarchy::new(2, 3).unwrap();
+    /// assert_eq!(
+    ///     hierarchy.levels().iter().map(|level| level.cardinality()).collect::<Vec<_>>(),
+    ///     vec![2, 3, 5]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HierarchyError::InvalidCardinality**: If `cardinality < 2`.
+    ///
+    /// ```
+    /// # use assessment::domain::{HierarchyError, LinguisticHierarchy};
+    /// assert_eq!(
+    ///     LinguisticHierarchy::new(1, 3),
+    ///     Err(HierarchyError::InvalidCardinality { cardinality: 1 })
+    /// );
+    /// ```
+    ///
+    /// **HierarchyError::NoLevels**: If `levels == 0`.
+    ///
+    /// ```
+    /// # use assessment::domain::{HierarchyError, LinguisticHierarchy};
+    /// assert_eq!(LinguisticHierarchy::new(3, 0), Err(HierarchyError::NoLevels));
+    /// ```
+    pub fn new(cardinality: usize, levels: usize) -> Result<Self, HierarchyError> {
+        if cardinality < 2 {
+            return Err(HierarchyError::InvalidCardinality { cardinality });
+        }
+        if levels == 0 {
+            return Err(HierarchyError::NoLevels);
+        }
+        let mut cardinality = cardinality;
+        let mut domains = Vec::with_capacity(levels);
+        for _ in 0..levels {
+            let names: Vec<String> = (0..cardinality).map(|i| format!("s{}", i)).collect();
+            domains.push(symmetric_domain(&names).unwrap());
+            cardinality = 2 * cardinality - 1;
+        }
+        Ok(Self { levels: domains })
+    }
+
+    /// Returns hierarchy levels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::LinguisticHierarchy;
+    /// let hierarchy = LinguisticHierarchy::new(3, 2).unwrap();
+    /// assert_eq!(hierarchy.levels()[1].get_labels_names(), vec!["s0", "s1", "s2", "s3", "s4"]);
+    /// ```
+    pub fn levels(&self) -> &[Qualitative<Trapezoidal>] {
+        &self.levels
+    }
+
+    /// Returns a level of the hierarchy.
+    ///
+    /// # Arguments
+    /// * `level`: Level number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::LinguisticHierarchy;
+    /// let hierarchy = LinguisticHierarchy::new(3, 2).unwrap();
+    /// assert_eq!(hierarchy.level(1).unwrap().cardinality(), 5);
+    /// assert_eq!(hierarchy.level(2), None);
+    /// ```
+    pub fn level(&self, level: usize) -> Option<&Qualitative<Trapezoidal>> {
+        self.levels.get(level)
+    }
+
+    /// Returns the level number of a domain, if it is one of the hierarchy levels.
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::LinguisticHierarchy;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let hierarchy = LinguisticHierarchy::new(3, 3).unwrap();
+    /// let domain = qualitative_symmetric_domain!["s0", "s1", "s2", "s3", "s4"].unwrap();
+    /// assert_eq!(hierarchy.level_of(&domain), Some(1));
+    ///
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// assert_eq!(hierarchy.level_of(&domain), None);
+    /// ```
+    pub fn level_of(&self, domain: &Qualitative<Trapezoidal>) -> Option<usize> {
+        self.levels.iter().position(|level| level == domain)
+    }
+
+    /// Transformation function TF<sub>t</sub><sup>t'</sup> of a 2-tuple valuation from its
+    /// level `t` to level `t'`,
+    /// Δ<sub>t'</sub>(Δ<sub>t</sub><sup>-1</sup>(x) · (n(t') - 1) / (n(t) - 1)).
+    ///
+    /// Granularities of hierarchy levels are related by powers of two, so transformations are
+    /// exact and can be inverted.
+    ///
+    /// # Arguments
+    /// * `valuation`: Valuation over a hierarchy level.
+    /// * `level`: Target level number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::domain::LinguisticHierarchy;
+    /// # use assessment::valuation::TwoTuple;
+    /// let hierarchy = LinguisticHierarchy::new(3, 3).unwrap();
+    /// let valuation = TwoTuple::new_by_label_index(hierarchy.level(0).unwrap(), 1, 0.3).unwrap();
+    ///
+    /// let finest = hierarchy.transform(&valuation, 2).unwrap();
+    /// assert_eq!(finest.to_string(), "(s5, 0.20)");
+    /// assert_eq!(hierarchy.transform(&finest, 1).unwrap().to_string(), "(s3, -0.40)");
+    /// assert_eq!(hierarchy.transform(&finest, 0).unwrap(), valuation);
+    /// ```
+    ///
+    /// Valuations may also own their domains:
+    ///
+    /// ```
+    /// # use assessment::domain::LinguisticHierarchy;
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let hierarchy = LinguisticHierarchy::new(3, 2).unwrap();
+    /// let level = Arc::new(qualitative_symmetric_domain!["s0", "s1", "s2", "s3", "s4"].unwrap());
+    /// let valuation = TwoTuple::new_shared_by_label_index(&level, 3, 0.0).unwrap();
+    ///
+    /// assert_eq!(hierarchy.transform(&valuation, 0).unwrap().to_string(), "(s2, -0.50)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HierarchyError::InvalidLevel**: If `level` is out of hierarchy range.
+    ///
+    /// ```
+    /// # use assessment::domain::{HierarchyError, LinguisticHierarchy};
+    /// # use assessment::valuation::TwoTuple;
+    /// let hierarchy = LinguisticHierarchy::new(3, 2).unwrap();
+    /// let valuation = TwoTuple::new_by_label_index(hierarchy.level(0).unwrap(), 1, 0.0).unwrap();
+    /// assert_eq!(
+    ///     hierarchy.transform(&valuation, 2),
+    ///     Err(HierarchyError::InvalidLevel { level: 2, levels: 2 })
+    /// );
+    /// ```
+    ///
+    /// **HierarchyError::UnknownDomain**: If valuation domain isn't a hierarchy level.
+    ///
+    /// ```
+    /// # use assessment::domain::{HierarchyError, LinguisticHierarchy};
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let hierarchy = LinguisticHierarchy::new(3, 2).unwrap();
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let valuation = TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap();
+    /// assert_eq!(hierarchy.transform(&valuation, 1), Err(HierarchyError::UnknownDomain));
+    /// ```
+    pub fn transform<H: DomainHandle<Qualitative<Trapezoidal>>>(
+        &self,
+        valuation: &TwoTuple<'_, Trapezoidal, H>,
+        level: usize,
+    ) -> Result<TwoTuple<'_, Trapezoidal>, HierarchyError> {
+        let valuation = valuation.as_borrowed();
+        let target = self.level(level).ok_or(HierarchyError::InvalidLevel {
+            level,
+            levels: self.levels.len(),
+        })?;
+        let source = self
+            .level_of(valuation.domain())
+            .ok_or(HierarchyError::UnknownDomain)?;
+
+        // Scaling by powers of two is exact in floating point arithmetic
+        let beta = valuation.inverse_delta() * 2f32.powi(level as i32 - source as i32);
+        Ok(TwoTuple::delta(target, beta.clamp(0.0, (target.cardinality() - 1) as f32)).unwrap())
+    }
+}
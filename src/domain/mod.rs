@@ -1,7 +1,10 @@
 //! Domains in which assessments are represented.
 
+pub use blts::{BltsError, BltsIncompatibility, BltsSelection};
+pub use hierarchy::{HierarchyError, LinguisticHierarchy};
 pub use qualitative::{Qualitative, QualitativeError};
 pub use quantitative::{Quantitative, QuantitativeError, QuantitativeLimit};
+use std::ops::Deref;
 
 /// Quantitative struct and related implementations.
 pub mod quantitative;
@@ -12,5 +15,18 @@ pub mod qualitative;
 /// Domain factories
 pub mod factories;
 
+/// Basic linguistic term sets (BLTS) selection.
+pub mod blts;
+
+/// Linguistic hierarchies.
+pub mod hierarchy;
+
 /// Base trait for domains.
 pub trait Domain {}
+
+/// Handle through which valuations reach their domain.
+///
+/// Plain references (`&'domain D`) are the default handle of every valuation. Reference-counted
+/// pointers (`Arc<D>`) are used by the `Shared*` valuations, which own their domain and therefore
+/// can be stored in long-lived structs or sent to other threads.
+pub trait DomainHandle<D> = Deref<Target = D> + Clone;
@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 use crate::fuzzy::membership::piecewise::{LinearFunction, PiecewiseLinearFunction};
 use crate::fuzzy::membership::Trapezoidal;
 use crate::fuzzy::{label::get_labels_names, Label, LabelMembership};
 use crate::utilities;
+use crate::utilities::parser::{ParseError, Parser};
 
 use super::Domain;
 
@@ -49,6 +51,89 @@ impl<T: LabelMembership + Display> Display for Qualitative<T> {
     }
 }
 
+/// Parses the format written by [Display]: `[name => (a, b, c), ...]`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::domain::Qualitative;
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::qualitative_symmetric_domain;
+/// let domain = qualitative_symmetric_domain!["none", "low", "medium", "high", "perfect"].unwrap();
+/// assert_eq!(format!("{}", domain).parse::<Qualitative<Trapezoidal>>(), Ok(domain));
+///
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e", "f", "g"].unwrap();
+/// assert_eq!(format!("{}", domain).parse::<Qualitative<Trapezoidal>>(), Ok(domain));
+///
+/// let domain: Qualitative<Trapezoidal> = "[]".parse().unwrap();
+/// assert_eq!(domain.cardinality(), 0);
+/// ```
+///
+/// # Errors
+///
+/// **ParseError::UnexpectedCharacter**: If the text doesn't follow the format.
+///
+/// ```
+/// # use assessment::domain::Qualitative;
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "[a => (0, 0, 1) b => (0, 1, 1)]".parse::<Qualitative<Trapezoidal>>(),
+///     Err(ParseError::UnexpectedCharacter {
+///         position: 16,
+///         expected: String::from("',' or ']'"),
+///         found: 'b'
+///     })
+/// );
+/// ```
+///
+/// **ParseError::InvalidValue**: If there are labels with duplicate names, at the second one.
+///
+/// ```
+/// # use assessment::domain::Qualitative;
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "[a => (0, 0, 1), a => (0, 1, 1)]".parse::<Qualitative<Trapezoidal>>(),
+///     Err(ParseError::InvalidValue {
+///         position: 17,
+///         reason: String::from("Duplicate label name a.")
+///     })
+/// );
+/// ```
+///
+/// See also [Label::from_str] for label errors.
+impl FromStr for Qualitative<Trapezoidal> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, |parser| {
+            parser.expect('[')?;
+            let mut labels = Vec::new();
+            let mut positions = Vec::new();
+            if !parser.accept(']') {
+                loop {
+                    parser.skip_whitespace();
+                    positions.push(parser.position());
+                    labels.push(Label::parse(parser)?);
+                    if parser.accept(']') {
+                        break;
+                    } else if !parser.accept(',') {
+                        return Err(parser.unexpected("',' or ']'"));
+                    }
+                }
+            }
+            let mut names = HashSet::new();
+            let duplicate = labels.iter().position(|label| !names.insert(label.name()));
+            let position = duplicate.map_or(0, |index| positions[index]);
+            Qualitative::new(labels).map_err(|error| ParseError::InvalidValue {
+                position,
+                reason: error.to_string(),
+            })
+        })
+    }
+}
+
 impl<T: LabelMembership> Qualitative<T> {
     /// Returns the first duplicate value.
     fn _find_duplicate(labels: &[&str]) -> Option<String> {
@@ -289,7 +374,7 @@ impl<T: LabelMembership> Qualitative<T> {
     /// }
     /// ```
     pub fn is_odd(&self) -> bool {
-        self.cardinality() % 2 != 0
+        !self.cardinality().is_multiple_of(2)
     }
 }
 
@@ -369,7 +454,7 @@ impl Qualitative<Trapezoidal> {
     /// # Examples
     ///
     /// ```
-    /// # use assessment::qualitative_domain;
+    /// # use assessment::{qualitative_domain, qualitative_symmetric_domain};
     /// for (d, e) in [
     ///     (qualitative_domain!["a" => vec![0.0, 0.25, 0.75, 1.0]], true),
     ///     (qualitative_domain!["a" => vec![0.0, 0.0, 0.5], "b" => vec![0.5, 1.0, 1.0]], true),
@@ -421,7 +506,7 @@ impl Qualitative<Trapezoidal> {
     /// # Examples
     ///
     /// ```
-    /// # use assessment::qualitative_domain;
+    /// # use assessment::{qualitative_domain, qualitative_symmetric_domain};
     /// for (d, e) in [
     ///     (qualitative_domain!["a" => vec![0.0, 0.25, 0.75, 1.0]], true),
     ///     (qualitative_domain!["a" => vec![0.0, 0.0, 0.5], "b" => vec![0.5, 1.0, 1.0]], true),
@@ -431,6 +516,10 @@ impl Qualitative<Trapezoidal> {
     /// ] {
     ///     assert_eq!(d.unwrap().is_uniform(), e);
     /// }
+    ///
+    /// // Limits rounded to 5 decimals: 1/6 -> 0.16667, 2/6 -> 0.33333...
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e", "f", "g"].unwrap();
+    /// assert!(domain.is_uniform());
     /// ```
     pub fn is_uniform(&self) -> bool {
         let cardinality = self.cardinality();
@@ -444,9 +533,11 @@ impl Qualitative<Trapezoidal> {
             (a + b - c - d) / 2.
         };
 
+        // One decimal less than limits precision, as symmetric domains limits are rounded to 5
+        // decimals and distances between them may differ in the last one
         let diff = compute_diff(1);
         for pos in 2..cardinality {
-            if !utilities::math::approx_equal_f32(diff, compute_diff(pos), 5) {
+            if !utilities::math::approx_equal_f32(diff, compute_diff(pos), 4) {
                 return false;
             }
         }
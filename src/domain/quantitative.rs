@@ -1,5 +1,7 @@
 use super::Domain;
+use crate::utilities::parser::{ParseError, Parser};
 use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
 
 /// Quantitative limits trait alias
 pub trait QuantitativeLimit = Copy + Display + Debug + PartialOrd;
@@ -35,6 +37,67 @@ impl<T: QuantitativeLimit + Display> Display for QuantitativeError<T> {
 
 impl<T: QuantitativeLimit> Domain for Quantitative<T> {}
 
+impl<T: QuantitativeLimit> Display for Quantitative<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.inf, self.sup)
+    }
+}
+
+/// Parses the format written by [Display]: `[inf, sup]`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::domain::Quantitative;
+/// let domain = Quantitative::new(-1.5, 1.0 / 3.0).unwrap();
+/// assert_eq!(format!("{}", domain).parse::<Quantitative<f64>>(), Ok(domain));
+///
+/// assert_eq!("[0, 10]".parse::<Quantitative<i32>>().unwrap(), Quantitative::new(0, 10).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// **ParseError::InvalidNumber**: If a limit can't be parsed as `T`.
+///
+/// ```
+/// # use assessment::domain::Quantitative;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "[0, 2.5]".parse::<Quantitative<i32>>(),
+///     Err(ParseError::InvalidNumber { position: 4, value: String::from("2.5") })
+/// );
+/// ```
+///
+/// **ParseError::InvalidValue**: If limits are rejected by [Quantitative::new].
+///
+/// ```
+/// # use assessment::domain::Quantitative;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "[10, 5]".parse::<Quantitative<i32>>(),
+///     Err(ParseError::InvalidValue { position: 0, reason: String::from("Inf (10) > Sup (5).") })
+/// );
+/// ```
+impl<T: QuantitativeLimit + FromStr> FromStr for Quantitative<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, |parser| {
+            parser.skip_whitespace();
+            let position = parser.position();
+            parser.expect('[')?;
+            let inf = parser.number::<T>()?;
+            parser.expect(',')?;
+            let sup = parser.number::<T>()?;
+            parser.expect(']')?;
+            Quantitative::new(inf, sup).map_err(|error| ParseError::InvalidValue {
+                position,
+                reason: error.to_string(),
+            })
+        })
+    }
+}
+
 // Note: + Copy added because clion doesn't detect here correctly the trait_alias feature
 impl<T: QuantitativeLimit + Copy> Quantitative<T> {
     /// Quantitative domain constructor.
@@ -1,6 +1,8 @@
 use crate::fuzzy::membership::piecewise::PiecewiseLinearFunction;
-use crate::fuzzy::membership::Membership;
+use crate::fuzzy::membership::{Membership, Trapezoidal};
+use crate::utilities::parser::{ParseError, Parser};
 use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
 
 /// Label's membership trait alias
 pub trait LabelMembership = Membership + Display;
@@ -21,6 +23,74 @@ impl<T: LabelMembership + Display> Display for Label<T> {
     }
 }
 
+/// Parses the format written by [Display]: `name => (a, b, c)`.
+///
+/// Spaces around the name are ignored. Names containing `=>` can't be parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::fuzzy::{Label, membership::Trapezoidal};
+/// let label = Label::new(
+///     String::from("very good"),
+///     Trapezoidal::new(vec![0.5, 0.75, 1.0, 1.0]).unwrap()
+/// ).unwrap();
+///
+/// assert_eq!(format!("{}", label).parse::<Label<Trapezoidal>>(), Ok(label));
+/// ```
+///
+/// # Errors
+///
+/// **ParseError::UnexpectedEnd**: If there is no `=>` delimiter.
+///
+/// ```
+/// # use assessment::fuzzy::{Label, membership::Trapezoidal};
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "a (0.0, 0.5, 1.0)".parse::<Label<Trapezoidal>>(),
+///     Err(ParseError::UnexpectedEnd { position: 17, expected: String::from("'=>'") })
+/// );
+/// ```
+///
+/// **ParseError::InvalidValue**: If the name is rejected by [Label::new].
+///
+/// ```
+/// # use assessment::fuzzy::{Label, membership::Trapezoidal};
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "  High => (0.0, 0.5, 1.0)".parse::<Label<Trapezoidal>>(),
+///     Err(ParseError::InvalidValue {
+///         position: 2,
+///         reason: String::from("Name 'High' isn't standardized.")
+///     })
+/// );
+/// ```
+///
+/// See also [Trapezoidal::from_str] for membership errors.
+impl FromStr for Label<Trapezoidal> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, Label::parse)
+    }
+}
+
+impl Label<Trapezoidal> {
+    /// Parses a label from `parser`.
+    pub(crate) fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        parser.skip_whitespace();
+        let position = parser.position();
+        let name = parser.until("=>")?;
+        parser.expect('=')?;
+        parser.expect('>')?;
+        let membership = Trapezoidal::parse(parser)?;
+        Label::new(String::from(name), membership).map_err(|error| ParseError::InvalidValue {
+            position,
+            reason: error.to_string(),
+        })
+    }
+}
+
 /// Label error types.
 #[derive(Debug, PartialEq)]
 pub enum LabelError {
@@ -204,8 +274,6 @@ pub fn get_labels_names<T: Display + LabelMembership>(labels: &[Label<T>]) -> Ve
         .collect::<Vec<&str>>()
 }
 
-#[allow(unused_imports)]
-use crate::fuzzy::membership::Trapezoidal;
 /// Trapezoidal labels.
 ///
 /// Generates an array of trapezoidal labels.
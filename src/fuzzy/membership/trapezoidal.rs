@@ -1,6 +1,8 @@
 use crate::fuzzy::membership::piecewise::{LinearFunction, PiecewiseLinearFunction};
 use crate::utilities;
+use crate::utilities::parser::{ParseError, Parser};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 use super::Membership;
 
@@ -59,17 +61,104 @@ impl Display for TrapezoidalError {
 }
 impl Membership for Trapezoidal {}
 
+/// Limits are written with two decimals, unless more are needed to parse them back.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// let membership = Trapezoidal::new(vec![0.0, 1.0 / 3.0, 1.0]).unwrap();
+/// assert_eq!(format!("{}", membership), "(0.00, 0.33333334, 1.00)");
+/// assert_eq!(format!("{}", membership).parse::<Trapezoidal>().unwrap(), membership);
+/// ```
 impl Display for Trapezoidal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.is_triangular() {
-            write!(f, "({:.2}, {:.2}, {:.2})", self.a, self.b, self.d)
+        let limits = if self.is_triangular() {
+            vec![self.a, self.b, self.d]
         } else {
-            write!(
-                f,
-                "({:.2}, {:.2}, {:.2}, {:.2})",
-                self.a, self.b, self.c, self.d
-            )
-        }
+            vec![self.a, self.b, self.c, self.d]
+        };
+        write!(
+            f,
+            "({})",
+            limits
+                .into_iter()
+                .map(format_limit)
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Formats a limit with two decimals, or with full precision if two decimals lose information.
+fn format_limit(limit: f32) -> String {
+    let short = format!("{:.2}", limit);
+    if short.parse::<f32>() == Ok(limit) {
+        short
+    } else {
+        format!("{}", limit)
+    }
+}
+
+/// Parses the format written by [Display]: `(a, b, c)` or `(a, b, c, d)`.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// assert_eq!(
+///     "(0.0, 0.25, 0.5)".parse::<Trapezoidal>().unwrap(),
+///     Trapezoidal::new(vec![0.0, 0.25, 0.5]).unwrap()
+/// );
+/// assert_eq!(
+///     " ( 0, 0.1,0.2 , 1e0 ) ".parse::<Trapezoidal>().unwrap(),
+///     Trapezoidal::new(vec![0.0, 0.1, 0.2, 1.0]).unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// **ParseError::UnexpectedCharacter**: If the text doesn't follow the format.
+///
+/// ```
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "(0.0, 0.5; 1.0)".parse::<Trapezoidal>(),
+///     Err(ParseError::UnexpectedCharacter {
+///         position: 9,
+///         expected: String::from("',' or ')'"),
+///         found: ';'
+///     })
+/// );
+/// ```
+///
+/// **ParseError::InvalidNumber**: If a limit isn't a number.
+///
+/// ```
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "(0.0, 0.5, 1.O)".parse::<Trapezoidal>(),
+///     Err(ParseError::InvalidNumber { position: 11, value: String::from("1.O") })
+/// );
+/// ```
+///
+/// **ParseError::InvalidValue**: If limits are rejected by [Trapezoidal::new].
+///
+/// ```
+/// # use assessment::fuzzy::membership::Trapezoidal;
+/// # use assessment::utilities::parser::ParseError;
+/// assert_eq!(
+///     "(0.0, 0.5)".parse::<Trapezoidal>().unwrap_err().position(),
+///     0
+/// );
+/// ```
+impl FromStr for Trapezoidal {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::parse_all(s, Trapezoidal::parse)
     }
 }
 
@@ -340,6 +429,60 @@ impl Trapezoidal {
             self.membership_value(min)
         }
     }
+
+    /// Returns maxmin value against `other` membership function, i.e., the height of their
+    /// intersection.
+    ///
+    /// # Arguments
+    /// * `other`: Membership function.
+    ///
+    /// ```
+    /// # use assessment::fuzzy::membership::Trapezoidal;
+    /// let t = Trapezoidal::new(vec![0.0, 0.1, 0.2, 0.5]).unwrap();
+    /// for (other, expected) in [
+    ///     (vec![0.5, 0.6, 0.7], 0.0),
+    ///     (vec![0.15, 0.3, 0.4], 0.78),
+    ///     (vec![0.15, 0.15, 0.4], 1.0),
+    ///     (vec![0.3, 0.5, 0.7], 0.4),
+    ///     (vec![0.4, 0.4, 0.4], 0.33),
+    /// ] {
+    ///     let other = Trapezoidal::new(other).unwrap();
+    ///     assert!((t.max_min_membership(&other) - expected).abs() < 0.01);
+    ///     assert!((other.max_min_membership(&t) - expected).abs() < 0.01);
+    /// }
+    /// ```
+    pub fn max_min_membership(&self, other: &Trapezoidal) -> f32 {
+        let (left, right) = if self.c < other.b {
+            (self, other)
+        } else if other.c < self.b {
+            (other, self)
+        } else {
+            return 1.0;
+        };
+        if left.d <= right.a {
+            0.0
+        } else {
+            (left.d - right.a) / ((left.d - left.c) + (right.b - right.a))
+        }
+    }
+
+    /// Parses a membership function from `parser`.
+    pub(crate) fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        parser.skip_whitespace();
+        let position = parser.position();
+        parser.expect('(')?;
+        let mut limits = vec![parser.number::<f32>()?];
+        while !parser.accept(')') {
+            if !parser.accept(',') {
+                return Err(parser.unexpected("',' or ')'"));
+            }
+            limits.push(parser.number::<f32>()?);
+        }
+        Trapezoidal::new(limits).map_err(|error| ParseError::InvalidValue {
+            position,
+            reason: error.to_string(),
+        })
+    }
 }
 
 /// Generates a PiecewiseLinearFunction from a trapezoidal membership.
@@ -364,14 +507,14 @@ impl From<&Trapezoidal> for PiecewiseLinearFunction {
         let (a, d) = t.coverage();
         let (b, c) = t.center();
 
-        let extremes = |f_0, f_1, plf: &mut PiecewiseLinearFunction| {
+        let extremes = |f_0: f64, f_1: f64, plf: &mut PiecewiseLinearFunction| {
             if f_0 != f_1 {
                 let slope = 1.0 / (f_1 - f_0);
-                let intercept = -1.0 * slope * f_0;
+                let intercept = -slope * f_0;
                 plf.add(
                     if f_0 < f_1 { f_0 } else { f_1 },
                     if f_0 < f_1 { f_1 } else { f_0 },
-                    LinearFunction::new(slope as f64, intercept as f64),
+                    LinearFunction::new(slope, intercept),
                 )
                 .unwrap();
             }
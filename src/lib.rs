@@ -4,7 +4,8 @@
 //! basic operations.
 //!
 //! Currently, the lib supports *numeric* and *interval* **quantitative** values
-//! and *linguistic*, *2-tuple* and *hesitant* **fuzzy** values.
+//! and *linguistic*, *2-tuple* and *hesitant* **fuzzy** values, as well as operators to aggregate
+//! them.
 //!
 //! Note that the library is a **Work In Progress**.
 
@@ -15,6 +16,7 @@ extern crate impl_ops;
 pub use domain::Domain;
 pub use valuation::Valuation;
 
+pub mod aggregation;
 pub mod domain;
 pub mod fuzzy;
 pub mod utilities;
@@ -3,3 +3,5 @@
 mod macros;
 /// Math utilities.
 pub mod math;
+/// Text parsing utilities.
+pub mod parser;
@@ -0,0 +1,238 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Text parsing errors.
+///
+/// Positions are zero-based character offsets in the parsed text.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// Unexpected character.
+    UnexpectedCharacter {
+        position: usize,
+        expected: String,
+        found: char,
+    },
+    /// Text ended too early.
+    UnexpectedEnd { position: usize, expected: String },
+    /// Malformed number.
+    InvalidNumber { position: usize, value: String },
+    /// Well-formed text describing an invalid value.
+    InvalidValue { position: usize, reason: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ParseError::*;
+        match &self {
+            UnexpectedCharacter {
+                position,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Expected {} at position {}, found '{}'.",
+                    expected, position, found
+                )
+            }
+            UnexpectedEnd { position, expected } => {
+                write!(
+                    f,
+                    "Expected {} at position {}, found end of text.",
+                    expected, position
+                )
+            }
+            InvalidNumber { position, value } => {
+                write!(f, "Invalid number '{}' at position {}.", value, position)
+            }
+            InvalidValue { position, reason } => {
+                write!(f, "Invalid value at position {}: {}", position, reason)
+            }
+        }
+    }
+}
+
+impl ParseError {
+    /// Returns error position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::fuzzy::membership::Trapezoidal;
+    /// # use assessment::utilities::parser::ParseError;
+    /// assert_eq!("(0.0, 0.5, x)".parse::<Trapezoidal>().unwrap_err().position(), 11);
+    /// ```
+    pub fn position(&self) -> usize {
+        use ParseError::*;
+        match self {
+            UnexpectedCharacter { position, .. }
+            | UnexpectedEnd { position, .. }
+            | InvalidNumber { position, .. }
+            | InvalidValue { position, .. } => *position,
+        }
+    }
+}
+
+/// Cursor over a text being parsed.
+pub(crate) struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a parser at the beginning of `text`.
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self { text, offset: 0 }
+    }
+
+    /// Parses the whole text with `parse`, rejecting trailing characters.
+    pub(crate) fn parse_all<T>(
+        text: &'a str,
+        parse: impl FnOnce(&mut Parser<'a>) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let mut parser = Parser::new(text);
+        let value = parse(&mut parser)?;
+        parser.end()?;
+        Ok(value)
+    }
+
+    /// Current position, as a character offset.
+    pub(crate) fn position(&self) -> usize {
+        self.text[..self.offset].chars().count()
+    }
+
+    /// Remaining text.
+    pub(crate) fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    /// Skips whitespaces.
+    pub(crate) fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// Next non-whitespace character, without consuming it.
+    pub(crate) fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    /// Error for the next non-whitespace character (or the end of text).
+    pub(crate) fn unexpected(&mut self, expected: &str) -> ParseError {
+        let expected = String::from(expected);
+        match self.peek() {
+            Some(found) => ParseError::UnexpectedCharacter {
+                position: self.position(),
+                expected,
+                found,
+            },
+            None => ParseError::UnexpectedEnd {
+                position: self.position(),
+                expected,
+            },
+        }
+    }
+
+    /// Consumes `expected` character.
+    pub(crate) fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.offset += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("'{}'", expected)))
+        }
+    }
+
+    /// Consumes `expected` character if it is the next one.
+    pub(crate) fn accept(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.offset += expected.len_utf8();
+        }
+        found
+    }
+
+    /// Consumes `words` if they are next, separated by any whitespaces and followed by a word
+    /// boundary.
+    pub(crate) fn words(&mut self, words: &str) -> bool {
+        let offset = self.offset;
+        for word in words.split_whitespace() {
+            self.skip_whitespace();
+            let bounded = self
+                .rest()
+                .strip_prefix(word)
+                .map(|tail| !tail.starts_with(char::is_alphanumeric));
+            if bounded != Some(true) {
+                self.offset = offset;
+                return false;
+            }
+            self.offset += word.len();
+        }
+        true
+    }
+
+    /// Consumes the longest of `candidates` (see [Parser::words]) and returns its index.
+    pub(crate) fn longest_words<'n>(
+        &mut self,
+        candidates: impl IntoIterator<Item = &'n str>,
+    ) -> Option<usize> {
+        let start = self.offset;
+        let mut longest: Option<(usize, usize)> = None;
+        for (index, candidate) in candidates.into_iter().enumerate() {
+            if self.words(candidate) {
+                if longest.is_none_or(|(_, end)| self.offset > end) {
+                    longest = Some((index, self.offset));
+                }
+                self.offset = start;
+            }
+        }
+        longest.map(|(index, end)| {
+            self.offset = end;
+            index
+        })
+    }
+
+    /// Consumes text until `delimiter` (not included) and returns it trimmed.
+    pub(crate) fn until(&mut self, delimiter: &str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        match self.rest().find(delimiter) {
+            Some(length) => {
+                let token = &self.rest()[..length];
+                self.offset += length;
+                Ok(token.trim_end())
+            }
+            None => Err(ParseError::UnexpectedEnd {
+                position: self.text.chars().count(),
+                expected: format!("'{}'", delimiter),
+            }),
+        }
+    }
+
+    /// Consumes a number.
+    pub(crate) fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let position = self.position();
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '+' || c == '-'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.unexpected("number"));
+        }
+        let token = &rest[..length];
+        self.offset += length;
+        token.parse::<T>().map_err(|_| ParseError::InvalidNumber {
+            position,
+            value: String::from(token),
+        })
+    }
+
+    /// Checks that only whitespaces remain.
+    pub(crate) fn end(&mut self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.unexpected("end of text")),
+        }
+    }
+}
@@ -1,17 +1,33 @@
 use crate::domain::quantitative::NORMALIZATION_DOMAIN;
-use crate::domain::{Quantitative, QuantitativeLimit};
+use crate::domain::{DomainHandle, Qualitative, Quantitative, QuantitativeLimit};
+use crate::fuzzy::membership::Trapezoidal;
+use crate::utilities::parser::{ParseError, Parser};
+use crate::valuation::{Unified, UnifiedError, ValuationKind};
 use crate::Valuation;
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, Sub};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// Interval valuation.
+///
+/// The domain is borrowed by default. See [SharedInterval] for valuations owning their domain.
 #[derive(Debug, PartialEq)]
-pub struct Interval<'domain, T: QuantitativeLimit> {
-    domain: &'domain Quantitative<T>,
+pub struct Interval<
+    'domain,
+    T: QuantitativeLimit,
+    H: DomainHandle<Quantitative<T>> = &'domain Quantitative<T>,
+> {
+    domain: H,
     min: T,
     max: T,
+    lifetime: PhantomData<&'domain ()>,
 }
 
+/// Interval valuation sharing the ownership of its domain.
+pub type SharedInterval<T> = Interval<'static, T, Arc<Quantitative<T>>>;
+
 /// Interval errors types.
 #[derive(Debug, PartialEq)]
 pub enum IntervalError<T: QuantitativeLimit> {
@@ -21,6 +37,8 @@ pub enum IntervalError<T: QuantitativeLimit> {
     InvalidMin { min: T, inf: T },
     /// Invalid maximum value.
     InvalidMax { max: T, sup: T },
+    /// Malformed valuation text.
+    InvalidSyntax { error: ParseError },
 }
 
 // Note: + Display added because clion doesn't detect here correctly the trait_alias feature
@@ -37,11 +55,68 @@ impl<T: QuantitativeLimit + Display> Display for IntervalError<T> {
             InvalidMax { max, sup } => {
                 write!(f, "Max ({}) > Sup ({}).", max, sup)
             }
+            InvalidSyntax { error } => {
+                write!(f, "Invalid syntax: {}", error)
+            }
         }
     }
 }
 
-impl<'domain, T: QuantitativeLimit> Valuation for Interval<'domain, T> {}
+impl<'domain, T: QuantitativeLimit, H: DomainHandle<Quantitative<T>>> Display
+    for Interval<'domain, T, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+/// Interval valuations as generic valuations.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::domain::Quantitative;
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Interval, ValuationKind};
+/// # use assessment::Valuation;
+/// let domain = Quantitative::new(0, 8).unwrap();
+/// let valuation: Box<dyn Valuation> = Box::new(Interval::new(&domain, 2, 4).unwrap());
+///
+/// assert_eq!(valuation.kind(), ValuationKind::Interval);
+/// assert_eq!(format!("{}", valuation), "[2, 4]");
+/// assert_eq!(valuation.normalization().value(), (0.25, 0.5));
+/// assert_eq!(format!("{}", valuation.negation()), "[4, 6]");
+///
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// assert_eq!(*valuation.unification(&blts).unwrap().measures(), vec![0.0, 1.0, 1.0, 0.0, 0.0]);
+/// ```
+impl<'domain, T: QuantitativeLimit + Into<f64>, H: DomainHandle<Quantitative<T>>> Valuation
+    for Interval<'domain, T, H>
+where
+    T: Mul<Output = T>,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Div<Output = T>,
+{
+    fn kind(&self) -> ValuationKind {
+        ValuationKind::Interval
+    }
+
+    fn normalization(&self) -> Interval<'static, f64> {
+        self.normalize()
+    }
+
+    fn negation(&self) -> Box<dyn Valuation + '_> {
+        Box::new(self.neg())
+    }
+
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        self.as_borrowed().unification(domain)
+    }
+}
 
 // Note: + <Trait> added because clion doesn't detect here correctly the trait_alias feature
 impl<
@@ -124,31 +199,78 @@ impl<
                 sup: domain.sup(),
             })
         } else {
-            Ok(Self { domain, min, max })
+            Ok(Self {
+                domain,
+                min,
+                max,
+                lifetime: PhantomData,
+            })
         }
     }
 
-    /// Returns valuation values.
+    /// Parses a valuation in `domain` from `text`, such as `[0.2, 0.5]`.
+    ///
+    /// # Arguments
+    /// * `domain`: A quantitative domain reference.
+    /// * `text`: Valuation text.
     ///
     /// # Examples
     ///
     /// ```
     /// # use assessment::valuation::Interval;
     /// # use assessment::domain::Quantitative;
-    /// let domain = Quantitative::new(1, 5).unwrap();
-    /// let valuation = Interval::new(&domain, 2, 3).unwrap();
-    /// assert_eq!(valuation.value(), (2, 3));
+    /// let domain = Quantitative::new(0.0, 1.0).unwrap();
+    /// assert_eq!(
+    ///     Interval::parse_in_domain(&domain, "[0.2, 0.5]"),
+    ///     Ok(Interval::new(&domain, 0.2, 0.5).unwrap())
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **IntervalError::InvalidSyntax**: If `text` isn't a pair of numbers between brackets.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Interval, IntervalError};
+    /// # use assessment::domain::Quantitative;
+    /// # use assessment::utilities::parser::ParseError;
+    /// let domain = Quantitative::new(0, 10).unwrap();
+    /// assert_eq!(
+    ///     Interval::parse_in_domain(&domain, "[2, 5"),
+    ///     Err(IntervalError::InvalidSyntax {
+    ///         error: ParseError::UnexpectedEnd { position: 5, expected: String::from("']'") }
+    ///     })
+    /// );
     /// ```
     ///
+    /// Other errors: see [Interval::new].
+    ///
     /// ```
-    /// # use assessment::valuation::Interval;
+    /// # use assessment::valuation::{Interval, IntervalError};
     /// # use assessment::domain::Quantitative;
-    /// let domain = Quantitative::new(1.0, 5.7).unwrap();
-    /// let valuation = Interval::new(&domain, 2.0, 3.0).unwrap();
-    /// assert_eq!(valuation.value(), (2.0, 3.0));
+    /// let domain = Quantitative::new(0, 10).unwrap();
+    /// assert_eq!(
+    ///     Interval::parse_in_domain(&domain, "[5, 2]"),
+    ///     Err(IntervalError::InvalidRange { min: 5, max: 2 })
+    /// );
     /// ```
-    pub fn value(&self) -> (T, T) {
-        (self.min, self.max)
+    pub fn parse_in_domain(
+        domain: &'domain Quantitative<T>,
+        text: &str,
+    ) -> Result<Self, IntervalError<T>>
+    where
+        T: FromStr,
+    {
+        let (min, max) = Parser::parse_all(text, |parser| {
+            parser.expect('[')?;
+            let min = parser.number::<T>()?;
+            parser.expect(',')?;
+            let max = parser.number::<T>()?;
+            parser.expect(']')?;
+            Ok((min, max))
+        })
+        .map_err(|error| IntervalError::InvalidSyntax { error })?;
+        Interval::new(domain, min, max)
     }
 
     /// Returns valuation domain.
@@ -165,7 +287,98 @@ impl<
     pub fn domain(&self) -> &'domain Quantitative<T> {
         self.domain
     }
+}
+
+// Note: + <Trait> added because clion doesn't detect here correctly the trait_alias feature
+impl<
+        T: QuantitativeLimit + Copy + Debug + Display + Into<f64> + Add<Output = T> + Sub<Output = T>,
+    > SharedInterval<T>
+{
+    /// Creates a new valuation sharing the ownership of `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted quantitative domain.
+    /// * `min`: Interval min value.
+    /// * `max`: Interval max value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Interval, SharedInterval};
+    /// # use assessment::domain::Quantitative;
+    /// # use std::sync::Arc;
+    /// let valuation: SharedInterval<i32> = {
+    ///     let domain = Arc::new(Quantitative::new(0, 10).unwrap());
+    ///     Interval::new_shared(&domain, 2, 4).unwrap()
+    /// };
+    ///
+    /// let handle = std::thread::spawn(move || valuation.neg().value());
+    /// assert_eq!(handle.join().unwrap(), (6, 8));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [Interval::new].
+    pub fn new_shared(
+        domain: &Arc<Quantitative<T>>,
+        min: T,
+        max: T,
+    ) -> Result<Self, IntervalError<T>> {
+        let valuation = Interval::new(domain, min, max)?;
+        Ok(Self {
+            domain: Arc::clone(domain),
+            min: valuation.min,
+            max: valuation.max,
+            lifetime: PhantomData,
+        })
+    }
 
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Interval;
+    /// # use assessment::domain::Quantitative;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(Quantitative::new(1.0, 5.7).unwrap());
+    /// let valuation = Interval::new_shared(&domain, 2.0, 3.0).unwrap();
+    /// assert_eq!(valuation.domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Quantitative<T> {
+        &self.domain
+    }
+}
+
+// Note: + <Trait> added because clion doesn't detect here correctly the trait_alias feature
+impl<
+        'domain,
+        T: QuantitativeLimit + Copy + Debug + Display + Into<f64> + Add<Output = T> + Sub<Output = T>,
+        H: DomainHandle<Quantitative<T>>,
+    > Interval<'domain, T, H>
+{
+    /// Returns valuation values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Interval;
+    /// # use assessment::domain::Quantitative;
+    /// let domain = Quantitative::new(1, 5).unwrap();
+    /// let valuation = Interval::new(&domain, 2, 3).unwrap();
+    /// assert_eq!(valuation.value(), (2, 3));
+    /// ```
+    ///
+    /// ```
+    /// # use assessment::valuation::Interval;
+    /// # use assessment::domain::Quantitative;
+    /// let domain = Quantitative::new(1.0, 5.7).unwrap();
+    /// let valuation = Interval::new(&domain, 2.0, 3.0).unwrap();
+    /// assert_eq!(valuation.value(), (2.0, 3.0));
+    /// ```
+    pub fn value(&self) -> (T, T) {
+        (self.min, self.max)
+    }
     /// Value normalized in domain 0.0 to 1.0.
     ///
     /// Note that the type of value is f64.
@@ -188,7 +401,7 @@ impl<
     /// assert_eq!(normalized.value(), (0.5, 1.0));
     /// assert_eq!(*normalized.domain(), NORMALIZATION_DOMAIN);
     /// ```
-    pub fn normalize(&self) -> Interval<f64> {
+    pub fn normalize(&self) -> Interval<'static, f64> {
         let normalize = |v: f64| {
             (v - self.domain.inf().into()) / (self.domain.sup().into() - self.domain.inf().into())
         };
@@ -196,6 +409,7 @@ impl<
             domain: &NORMALIZATION_DOMAIN,
             min: normalize(self.min.into()),
             max: normalize(self.max.into()),
+            lifetime: PhantomData,
         }
     }
 
@@ -213,9 +427,34 @@ impl<
     /// ```
     pub fn neg(&self) -> Self {
         Self {
-            domain: self.domain,
+            domain: self.domain.clone(),
             min: self.domain.sup() + self.domain.inf() - self.max,
             max: self.domain.sup() + self.domain.inf() - self.min,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Borrowed view of the valuation.
+    ///
+    /// Gives access to the API only available for borrowed domains (unification, conversions...).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Interval, Numeric};
+    /// # use assessment::domain::Quantitative;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(Quantitative::new(0, 10).unwrap());
+    /// let valuation = Interval::new_shared(&domain, 2, 4).unwrap();
+    /// assert_eq!(valuation.as_borrowed(), Interval::new(&domain, 2, 4).unwrap());
+    /// assert_eq!(Numeric::try_from(&valuation.as_borrowed()).unwrap().value(), 3);
+    /// ```
+    pub fn as_borrowed(&self) -> Interval<'_, T> {
+        Interval {
+            domain: &self.domain,
+            min: self.min,
+            max: self.max,
+            lifetime: PhantomData,
         }
     }
 }
@@ -237,3 +476,18 @@ impl<'domain> Interval<'domain, i32> {
         (self.max / 2) + (self.min / 2)
     }
 }
+
+impl<'domain, H: DomainHandle<Quantitative<f64>>> Interval<'domain, f64, H> {
+    /// Valuation over the same domain given limits normalized in domain 0.0 to 1.0 (see
+    /// [Interval::normalize]), saturated to the domain range.
+    pub(crate) fn denormalize(&self, min: f64, max: f64) -> Self {
+        let (inf, sup) = (self.domain.inf(), self.domain.sup());
+        let denormalize = |v: f64| (inf + v * (sup - inf)).clamp(inf, sup);
+        Self {
+            domain: self.domain.clone(),
+            min: denormalize(min),
+            max: denormalize(max.max(min)),
+            lifetime: PhantomData,
+        }
+    }
+}
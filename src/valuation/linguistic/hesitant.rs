@@ -1,15 +1,34 @@
-use crate::domain::Qualitative;
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::label::standardize_name;
+use crate::fuzzy::membership::Trapezoidal;
+use crate::fuzzy::Label;
 use crate::fuzzy::LabelMembership;
-use crate::valuation::Linguistic;
+use crate::utilities;
+use crate::utilities::parser::Parser;
+use crate::valuation::{Interval, Linguistic, Unified, UnifiedError, ValuationKind};
 use crate::Valuation;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Hesitant linguistic valuation.
+///
+/// The domain is borrowed by default. See [SharedHesitant] for valuations owning their domain.
 #[derive(Debug, PartialEq)]
-pub struct Hesitant<'domain, T: LabelMembership> {
-    relation: HesitantRelation<'domain, T>,
+pub struct Hesitant<
+    'domain,
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>> = &'domain Qualitative<T>,
+> {
+    domain: H,
+    relation: Relation,
+    lifetime: PhantomData<&'domain Qualitative<T>>,
 }
 
+/// Hesitant linguistic valuation sharing the ownership of its domain.
+pub type SharedHesitant<T> = Hesitant<'static, T, Arc<Qualitative<T>>>;
+
 /// Hesitant relation type.
 #[derive(Debug, PartialEq)]
 pub enum HesitantRelation<'domain, T: LabelMembership> {
@@ -40,6 +59,74 @@ pub enum HesitantRelation<'domain, T: LabelMembership> {
     },
 }
 
+/// Policy used to map hesitant valuations bounds between domains when they don't land exactly
+/// on a label.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HesitantTransformPolicy {
+    /// Widens the valuation to the closest labels outside the bounds.
+    Conservative,
+    /// Moves each bound to its nearest label.
+    Nearest,
+}
+
+/// Hesitant relation stored by valuations, detached from its domain.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Relation {
+    SingleValue(usize),
+    AtLeast(usize),
+    AtMost(usize),
+    Between(usize, usize),
+    LowerThan(usize),
+    GreaterThan(usize),
+}
+
+impl Relation {
+    /// Attaches the relation to `domain`.
+    fn in_domain<T: LabelMembership>(self, domain: &Qualitative<T>) -> HesitantRelation<'_, T> {
+        use HesitantRelation::*;
+        match self {
+            Relation::SingleValue(index) => SingleValue { domain, index },
+            Relation::AtLeast(index) => AtLeast { domain, index },
+            Relation::AtMost(index) => AtMost { domain, index },
+            Relation::Between(lower, upper) => Between {
+                domain,
+                lower,
+                upper,
+            },
+            Relation::LowerThan(index) => LowerThan { domain, index },
+            Relation::GreaterThan(index) => GreaterThan { domain, index },
+        }
+    }
+
+    /// Closest relation covering labels `[lower, upper]` in a domain of `cardinality` labels.
+    fn covering(lower: usize, upper: usize, cardinality: usize) -> Self {
+        use Relation::*;
+        if lower == upper {
+            SingleValue(lower)
+        } else if lower == 0 && upper < cardinality - 1 {
+            AtMost(upper)
+        } else if lower > 0 && upper == cardinality - 1 {
+            AtLeast(lower)
+        } else {
+            Between(lower, upper)
+        }
+    }
+
+    /// Mirrored relation in a domain of `cardinality` labels.
+    fn mirrored(self, cardinality: usize) -> Self {
+        use Relation::*;
+        let last = cardinality - 1;
+        match self {
+            SingleValue(index) => SingleValue(last - index),
+            AtLeast(index) => AtMost(last - index),
+            AtMost(index) => AtLeast(last - index),
+            Between(lower, upper) => Between(last - upper, last - lower),
+            LowerThan(index) => GreaterThan(last - index),
+            GreaterThan(index) => LowerThan(last - index),
+        }
+    }
+}
+
 impl<'domain, T: LabelMembership> HesitantRelation<'domain, T> {
     /// Validate hesitant relation.
     ///
@@ -298,6 +385,206 @@ impl<'domain, T: LabelMembership> HesitantRelation<'domain, T> {
             } => domain,
         }
     }
+
+    /// Parses a relation in `domain` from a comparative linguistic expression.
+    ///
+    /// Expressions follow the context-free grammar
+    ///
+    /// ```text
+    /// <expression> ::= <label>
+    ///                | "at least" <label>
+    ///                | "at most" <label>
+    ///                | "lower than" <label>
+    ///                | "greater than" <label>
+    ///                | "between" <label> "and" <label>
+    /// <label>      ::= any label name of the domain
+    /// ```
+    ///
+    /// Text is case insensitive and words can be separated by any whitespaces. When several label
+    /// names match (e.g. `high` and `high enough`), the longest one is chosen. Parsed relations are
+    /// validated, and its [Display] renders them back to an equivalent sentence.
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain reference.
+    /// * `text`: Comparative linguistic expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::HesitantRelation;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["bad", "low", "medium", "good", "high", "very high"].unwrap();
+    ///
+    /// for (text, relation) in [
+    ///     ("at least high", HesitantRelation::AtLeast { domain: &domain, index: 4 }),
+    ///     ("at most low", HesitantRelation::AtMost { domain: &domain, index: 1 }),
+    ///     ("Between  medium and VERY high", HesitantRelation::Between { domain: &domain, lower: 2, upper: 5 }),
+    ///     ("lower than good", HesitantRelation::LowerThan { domain: &domain, index: 3 }),
+    ///     ("greater than bad", HesitantRelation::GreaterThan { domain: &domain, index: 0 }),
+    ///     ("very high", HesitantRelation::SingleValue { domain: &domain, index: 5 }),
+    /// ] {
+    ///     assert_eq!(HesitantRelation::parse_in_domain(&domain, text), Ok(relation));
+    /// }
+    ///
+    /// let relation = HesitantRelation::parse_in_domain(&domain, " between low   and high ").unwrap();
+    /// assert_eq!(relation.to_string(), "between low and high");
+    /// assert_eq!(HesitantRelation::parse_in_domain(&domain, &relation.to_string()), Ok(relation));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::InvalidName**: If a label name isn't contained in domain's labels, or
+    /// `text` doesn't follow the grammar (the name is then the text where a label was expected).
+    ///
+    /// ```
+    /// # use assessment::valuation::{HesitantRelation, HesitantError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// for (text, name) in [
+    ///     ("at least very high", "very high"),
+    ///     ("between good and high", "good"),
+    ///     ("at best", "at best"),
+    ///     ("between low or high", "low or high"),
+    ///     ("at most", ""),
+    /// ] {
+    ///     assert_eq!(
+    ///         HesitantRelation::parse_in_domain(&domain, text),
+    ///         Err(HesitantError::InvalidName { domain: &domain, name: String::from(name) })
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// Other errors: see [HesitantRelation::validate].
+    ///
+    /// ```
+    /// # use assessment::valuation::{HesitantRelation, HesitantError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     HesitantRelation::parse_in_domain(&domain, "lower than low"),
+    ///     Err(HesitantError::InvalidIndex { domain: &domain, index: 0 })
+    /// );
+    /// ```
+    pub fn parse_in_domain(
+        domain: &'domain Qualitative<T>,
+        text: &str,
+    ) -> Result<Self, HesitantError<'domain, T>> {
+        let text = text.to_lowercase();
+        HesitantRelation::expression(&mut Parser::new(&text), domain)?.validate()
+    }
+
+    /// Parses `<expression>` (see [HesitantRelation::parse_in_domain]).
+    fn expression(
+        parser: &mut Parser,
+        domain: &'domain Qualitative<T>,
+    ) -> Result<Self, HesitantError<'domain, T>> {
+        use HesitantRelation::*;
+        let relation = if parser.words("at least") {
+            AtLeast {
+                domain,
+                index: HesitantRelation::label(parser, domain, false)?,
+            }
+        } else if parser.words("at most") {
+            AtMost {
+                domain,
+                index: HesitantRelation::label(parser, domain, false)?,
+            }
+        } else if parser.words("lower than") {
+            LowerThan {
+                domain,
+                index: HesitantRelation::label(parser, domain, false)?,
+            }
+        } else if parser.words("greater than") {
+            GreaterThan {
+                domain,
+                index: HesitantRelation::label(parser, domain, false)?,
+            }
+        } else if parser.words("between") {
+            Between {
+                domain,
+                lower: HesitantRelation::label(parser, domain, true)?,
+                upper: HesitantRelation::label(parser, domain, false)?,
+            }
+        } else {
+            SingleValue {
+                domain,
+                index: HesitantRelation::label(parser, domain, false)?,
+            }
+        };
+        Ok(relation)
+    }
+
+    /// Parses `<label>` (see [HesitantRelation::parse_in_domain]), followed by `and` if
+    /// `conjunction` or by the end of text otherwise.
+    fn label(
+        parser: &mut Parser,
+        domain: &'domain Qualitative<T>,
+        conjunction: bool,
+    ) -> Result<usize, HesitantError<'domain, T>> {
+        parser.skip_whitespace();
+        let rest = parser.rest();
+        let index = parser.longest_words(domain.get_labels_names());
+        let followed = if conjunction {
+            parser.words("and")
+        } else {
+            parser.end().is_ok()
+        };
+        match index {
+            Some(index) if followed => Ok(index),
+            _ => {
+                let name = if conjunction {
+                    rest.split(" and ").next().unwrap_or(rest)
+                } else {
+                    rest
+                };
+                Err(HesitantError::InvalidName {
+                    domain,
+                    name: standardize_name(name),
+                })
+            }
+        }
+    }
+
+    /// Relation detached from its domain.
+    fn detached(&self) -> Relation {
+        use HesitantRelation::*;
+        match *self {
+            SingleValue { domain: _, index } => Relation::SingleValue(index),
+            AtLeast { domain: _, index } => Relation::AtLeast(index),
+            AtMost { domain: _, index } => Relation::AtMost(index),
+            Between {
+                domain: _,
+                lower,
+                upper,
+            } => Relation::Between(lower, upper),
+            LowerThan { domain: _, index } => Relation::LowerThan(index),
+            GreaterThan { domain: _, index } => Relation::GreaterThan(index),
+        }
+    }
+}
+
+impl<'domain, T: LabelMembership> Display for HesitantRelation<'domain, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use HesitantRelation::*;
+        let name = |index: usize| match self.domain().get_label_by_index(index) {
+            Some(label) => label.name().clone(),
+            None => format!("#{}", index),
+        };
+        match *self {
+            SingleValue { domain: _, index } => write!(f, "{}", name(index)),
+            AtLeast { domain: _, index } => write!(f, "at least {}", name(index)),
+            AtMost { domain: _, index } => write!(f, "at most {}", name(index)),
+            LowerThan { domain: _, index } => write!(f, "lower than {}", name(index)),
+            GreaterThan { domain: _, index } => write!(f, "greater than {}", name(index)),
+            Between {
+                domain: _,
+                lower,
+                upper,
+            } => write!(f, "between {} and {}", name(lower), name(upper)),
+        }
+    }
 }
 
 /// Hesitant errors types.
@@ -315,10 +602,121 @@ pub enum HesitantError<'domain, T: LabelMembership> {
         domain: &'domain Qualitative<T>,
         name: String,
     },
+    /// Different domains where the same one was expected.
+    DomainMismatch {
+        expected: &'domain Qualitative<T>,
+        found: &'domain Qualitative<T>,
+    },
+    /// Invalid OWA weights parameter.
+    InvalidOwaParameter { alpha: f32 },
+}
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Display
+    for Hesitant<'domain, T, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.relation())
+    }
 }
 
-impl<'domain, T: LabelMembership> Linguistic for Hesitant<'domain, T> {}
-impl<'domain, T: LabelMembership> Valuation for Hesitant<'domain, T> {}
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Linguistic
+    for Hesitant<'domain, T, H>
+{
+    fn cardinality(&self) -> usize {
+        self.domain.cardinality()
+    }
+
+    fn symbolic_range(&self) -> (f32, f32) {
+        let (lower, upper) = self.indexes();
+        (lower as f32, upper as f32)
+    }
+}
+
+/// Hesitant valuations as generic valuations.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Hesitant, HesitantRelation, ValuationKind};
+/// # use assessment::Valuation;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuation: Box<dyn Valuation> = Box::new(
+///     Hesitant::new(HesitantRelation::AtLeast { domain: &domain, index: 1 }).unwrap()
+/// );
+///
+/// assert_eq!(valuation.kind(), ValuationKind::Hesitant);
+/// assert_eq!(format!("{}", valuation), "at least b");
+/// assert_eq!(valuation.normalization().value(), (0.5, 1.0));
+/// assert_eq!(format!("{}", valuation.negation()), "at most b");
+///
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// assert_eq!(*valuation.unification(&blts).unwrap().measures(), vec![0.0, 0.0, 1.0, 1.0, 1.0]);
+/// ```
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Hesitant, HesitantRelation};
+/// # use assessment::Valuation;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// for (relation, negation) in [
+///     (HesitantRelation::SingleValue { domain: &domain, index: 1 }, "d"),
+///     (HesitantRelation::AtMost { domain: &domain, index: 1 }, "at least d"),
+///     (HesitantRelation::LowerThan { domain: &domain, index: 1 }, "greater than d"),
+///     (HesitantRelation::GreaterThan { domain: &domain, index: 3 }, "lower than b"),
+///     (HesitantRelation::Between { domain: &domain, lower: 0, upper: 2 }, "between c and e"),
+/// ] {
+///     assert_eq!(Hesitant::new(relation).unwrap().negation().to_string(), negation);
+/// }
+/// ```
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Valuation
+    for Hesitant<'domain, T, H>
+{
+    fn kind(&self) -> ValuationKind {
+        ValuationKind::Hesitant
+    }
+
+    fn normalization(&self) -> Interval<'static, f64> {
+        super::normalize_symbolic_range(self)
+    }
+
+    fn negation(&self) -> Box<dyn Valuation + '_> {
+        Box::new(self.neg())
+    }
+
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        self.as_borrowed().unification_in_domain(domain)
+    }
+}
+
+/// Hesitant valuations are ordered when defined over the same domain (see [Hesitant::compare]).
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::Hesitant;
+/// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+/// let other = qualitative_symmetric_domain!["bad", "good"].unwrap();
+///
+/// let low = Hesitant::parse_in_domain(&domain, "low").unwrap();
+/// let at_least_medium = Hesitant::parse_in_domain(&domain, "at least medium").unwrap();
+/// let bad = Hesitant::parse_in_domain(&other, "bad").unwrap();
+///
+/// assert!(low < at_least_medium);
+/// assert_eq!(low.partial_cmp(&bad), None);
+/// ```
+impl<'domain, H> PartialOrd for Hesitant<'domain, Trapezoidal, H>
+where
+    H: DomainHandle<Qualitative<Trapezoidal>> + PartialEq,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare(other).ok()
+    }
+}
 
 // Note: + Display added because clion doesn't detect here correctly the trait_alias feature
 impl<'domain, T: LabelMembership> Display for HesitantError<'domain, T> {
@@ -344,6 +742,20 @@ impl<'domain, T: LabelMembership> Display for HesitantError<'domain, T> {
                     domain.get_labels_names()
                 )
             }
+            DomainMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Domain {} differs from expected domain {}.",
+                    found, expected
+                )
+            }
+            InvalidOwaParameter { alpha } => {
+                write!(
+                    f,
+                    "Invalid OWA weights parameter {} (should be in range [0, 1]).",
+                    alpha
+                )
+            }
         }
     }
 }
@@ -385,9 +797,160 @@ impl<'domain, T: LabelMembership> Hesitant<'domain, T> {
     ///
     pub fn new(relation: HesitantRelation<'domain, T>) -> Result<Self, HesitantError<'domain, T>> {
         let relation = relation.validate()?;
-        Ok(Self { relation })
+        Ok(Self {
+            domain: relation.domain(),
+            relation: relation.detached(),
+            lifetime: PhantomData,
+        })
     }
 
+    /// Parses a valuation in `domain` from a comparative linguistic expression, such as
+    /// `between low and high` or `medium`.
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain reference.
+    /// * `text`: Comparative linguistic expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Hesitant::parse_in_domain(&domain, "Between low and high"),
+    ///     Hesitant::new(HesitantRelation::Between { domain: &domain, lower: 0, upper: 2 })
+    /// );
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&domain, "at least  Medium").unwrap();
+    /// assert_eq!(valuation.to_string(), "at least medium");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [HesitantRelation::parse_in_domain].
+    pub fn parse_in_domain(
+        domain: &'domain Qualitative<T>,
+        text: &str,
+    ) -> Result<Self, HesitantError<'domain, T>> {
+        Hesitant::new(HesitantRelation::parse_in_domain(domain, text)?)
+    }
+
+    /// Valuation in `domain` with the closest relation covering labels `[lower, upper]`.
+    pub(crate) fn covering_in_domain(
+        domain: &'domain Qualitative<T>,
+        lower: usize,
+        upper: usize,
+    ) -> Self {
+        Self {
+            domain,
+            relation: Relation::covering(lower, upper, domain.cardinality()),
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::qualitative_domain;
+    /// let domain = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 1.0],
+    ///     "b" => vec![0.0, 1.0, 1.0]
+    /// ].unwrap();
+    ///
+    /// assert_eq!(
+    ///     *Hesitant::new(HesitantRelation::SingleValue { domain: &domain, index: 0 })
+    ///         .unwrap()
+    ///         .domain(),
+    ///     domain
+    /// );
+    /// ```
+    pub fn domain(&self) -> &'domain Qualitative<T> {
+        self.domain
+    }
+}
+
+impl<T: LabelMembership> SharedHesitant<T> {
+    /// Creates a new valuation given a `relation` in a shared `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `relation`: A hesitant relation in `domain`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation, SharedHesitant};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let valuation: SharedHesitant<_> = {
+    ///     let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    ///     Hesitant::new_shared(&domain, HesitantRelation::AtLeast { domain: &domain, index: 1 })
+    ///         .unwrap()
+    /// };
+    ///
+    /// let handle = std::thread::spawn(move || valuation.labels());
+    /// assert_eq!(handle.join().unwrap(), (String::from("b"), String::from("c")));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::DomainMismatch**: If `relation` isn't defined over `domain` itself.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantError, HesitantRelation};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Hesitant::new_shared(&domain, HesitantRelation::AtLeast { domain: &other, index: 1 }),
+    ///     Err(HesitantError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    ///
+    /// See also [HesitantRelation::validate].
+    pub fn new_shared<'a>(
+        domain: &'a Arc<Qualitative<T>>,
+        relation: HesitantRelation<'a, T>,
+    ) -> Result<Self, HesitantError<'a, T>> {
+        if !std::ptr::eq(relation.domain(), domain.as_ref()) {
+            return Err(HesitantError::DomainMismatch {
+                expected: domain,
+                found: relation.domain(),
+            });
+        }
+        let relation = relation.validate()?;
+        Ok(Self {
+            domain: Arc::clone(domain),
+            relation: relation.detached(),
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let relation = HesitantRelation::SingleValue { domain: &domain, index: 0 };
+    /// assert_eq!(Hesitant::new_shared(&domain, relation).unwrap().domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Qualitative<T> {
+        &self.domain
+    }
+}
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Hesitant<'domain, T, H> {
     /// Returns associated valuation indexes in domain.
     ///
     /// It returns (first index, last index): (usize, usize).
@@ -418,7 +981,7 @@ impl<'domain, T: LabelMembership> Hesitant<'domain, T> {
     /// ```
     pub fn indexes(&self) -> (usize, usize) {
         use HesitantRelation::*;
-        match self.relation {
+        match self.relation() {
             SingleValue { domain: _, index } => (index, index),
             AtLeast { domain, index } => (index, domain.cardinality() - 1),
             AtMost { domain: _, index } => (0, index),
@@ -465,33 +1028,575 @@ impl<'domain, T: LabelMembership> Hesitant<'domain, T> {
     /// ```
     pub fn labels(&self) -> (String, String) {
         let (lower, upper) = self.indexes();
-        let domain = self.relation.domain();
+        let domain = &self.domain;
         (
             domain.get_label_by_index(lower).unwrap().name().clone(),
             domain.get_label_by_index(upper).unwrap().name().clone(),
         )
     }
 
-    /// Returns valuation domain.
+    /// Returns valuation relation.
     ///
     /// # Examples
     ///
     /// ```
     /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Hesitant::new(HesitantRelation::AtMost { domain: &domain, index: 1 }).unwrap().relation(),
+    ///     HesitantRelation::AtMost { domain: &domain, index: 1 }
+    /// );
+    /// ```
+    pub fn relation(&self) -> HesitantRelation<'_, T> {
+        self.relation.in_domain(&self.domain)
+    }
+
+    /// Valuation negation.
+    ///
+    /// The relation is mirrored: e.g., `at least s_i` becomes `at most s_(g-i)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Hesitant;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// for (text, negation) in [
+    ///     ("b", "d"),
+    ///     ("at least b", "at most d"),
+    ///     ("at most a", "at least e"),
+    ///     ("lower than c", "greater than c"),
+    ///     ("between a and c", "between c and e"),
+    /// ] {
+    ///     assert_eq!(
+    ///         Hesitant::parse_in_domain(&domain, text).unwrap().neg(),
+    ///         Hesitant::parse_in_domain(&domain, negation).unwrap()
+    ///     );
+    /// }
+    /// ```
+    pub fn neg(&self) -> Self {
+        self.with_relation(self.relation.mirrored(self.domain.cardinality()))
+    }
+
+    /// Union of valuations, as the convex hull of their linguistic intervals.
+    ///
+    /// The result is given by the closest relation covering the same labels: a single label,
+    /// `at most`, `at least` or `between`.
+    ///
+    /// # Arguments
+    /// * `other`: Hesitant valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Hesitant;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |text| Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///
+    /// for (h1, h2, union) in [
+    ///     ("b", "b", "b"),
+    ///     ("b", "d", "between b and d"),
+    ///     ("lower than b", "c", "at most c"),
+    ///     ("greater than c", "between b and c", "at least b"),
+    ///     ("at most b", "at least d", "between a and e"),
+    /// ] {
+    ///     assert_eq!(valuation(h1).union(&valuation(h2)), Ok(valuation(union)));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, HesitantError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Hesitant::parse_in_domain(&domain, "b")
+    ///         .unwrap()
+    ///         .union(&Hesitant::parse_in_domain(&other, "b").unwrap()),
+    ///     Err(HesitantError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn union<'a>(&'a self, other: &'a Self) -> Result<Self, HesitantError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        let (l1, u1) = self.indexes();
+        let (l2, u2) = other.indexes();
+        Ok(self.covering(l1.min(l2), u1.max(u2)))
+    }
+
+    /// Intersection of valuations.
+    ///
+    /// The result is given by the closest relation covering the common labels, or `None` if there
+    /// is none.
+    ///
+    /// # Arguments
+    /// * `other`: Hesitant valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Hesitant;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |text| Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///
+    /// for (h1, h2, intersection) in [
+    ///     ("b", "b", Some("b")),
+    ///     ("b", "d", None),
+    ///     ("at most c", "at least c", Some("c")),
+    ///     ("greater than a", "between a and d", Some("between b and d")),
+    ///     ("lower than d", "lower than e", Some("at most c")),
+    /// ] {
+    ///     assert_eq!(
+    ///         valuation(h1).intersection(&valuation(h2)),
+    ///         Ok(intersection.map(valuation))
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, HesitantError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Hesitant::parse_in_domain(&domain, "b")
+    ///         .unwrap()
+    ///         .intersection(&Hesitant::parse_in_domain(&other, "b").unwrap()),
+    ///     Err(HesitantError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Result<Option<Self>, HesitantError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        let (l1, u1) = self.indexes();
+        let (l2, u2) = other.indexes();
+        let (lower, upper) = (l1.max(l2), u1.min(u2));
+        Ok((lower <= upper).then(|| self.covering(lower, upper)))
+    }
+
+    /// Complement of the valuation in its domain.
+    ///
+    /// Uncovered labels may be split in two pieces, one below and one above the valuation, each one
+    /// given by its closest relation. The complement of a valuation covering the whole domain is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Hesitant;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |text| Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///
+    /// for (h, complement) in [
+    ///     ("c", vec!["at most b", "at least d"]),
+    ///     ("at least b", vec!["a"]),
+    ///     ("lower than e", vec!["e"]),
+    ///     ("between b and c", vec!["a", "at least d"]),
+    ///     ("between a and e", vec![]),
+    /// ] {
+    ///     assert_eq!(
+    ///         valuation(h).complement(),
+    ///         complement.into_iter().map(valuation).collect::<Vec<_>>()
+    ///     );
+    /// }
+    /// ```
+    pub fn complement(&self) -> Vec<Self> {
+        let (lower, upper) = self.indexes();
+        let mut pieces = Vec::new();
+        if lower > 0 {
+            pieces.push(self.covering(0, lower - 1));
+        }
+        if upper < self.domain.cardinality() - 1 {
+            pieces.push(self.covering(upper + 1, self.domain.cardinality() - 1));
+        }
+        pieces
+    }
+
+    /// Returns the preference (possibility) degree of `self` over `other`.
+    ///
+    /// Given the linguistic intervals `[s_l1, s_u1]` and `[s_l2, s_u2]` of both valuations, it is
+    /// computed as **P(h1 ≥ h2) = max(0, min(1, (u1 - l2) / ((u1 - l1) + (u2 - l2))))**. When both
+    /// valuations are single labels it is 1, 0.5 or 0 depending on which one is greater.
+    ///
+    /// It holds that `P(h1 ≥ h2) + P(h2 ≥ h1) == 1`.
+    ///
+    /// # Arguments
+    /// * `other`: Hesitant valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Hesitant;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |text| Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///
+    /// for (h1, h2, degree) in [
+    ///     ("between b and d", "between a and c", 0.75),
+    ///     ("between a and c", "between b and d", 0.25),
+    ///     ("at least b", "at least b", 0.5),
+    ///     ("at most b", "greater than b", 0.0),
+    ///     ("c", "b", 1.0),
+    ///     ("b", "b", 0.5),
+    /// ] {
+    ///     assert_eq!(valuation(h1).preference_degree(&valuation(h2)), Ok(degree));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, HesitantError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Hesitant::parse_in_domain(&domain, "b")
+    ///         .unwrap()
+    ///         .preference_degree(&Hesitant::parse_in_domain(&other, "b").unwrap()),
+    ///     Err(HesitantError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn preference_degree<'a>(&'a self, other: &'a Self) -> Result<f32, HesitantError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        let (l1, u1) = self.indexes();
+        let (l2, u2) = other.indexes();
+        let lengths = ((u1 - l1) + (u2 - l2)) as f32;
+        Ok(if lengths == 0.0 {
+            match l1.cmp(&l2) {
+                Ordering::Less => 0.0,
+                Ordering::Equal => 0.5,
+                Ordering::Greater => 1.0,
+            }
+        } else {
+            ((u1 as f32 - l2 as f32) / lengths).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Valuation over the same domain covering labels `[lower, upper]`.
+    fn covering(&self, lower: usize, upper: usize) -> Self {
+        self.with_relation(Relation::covering(lower, upper, self.domain.cardinality()))
+    }
+
+    /// Valuation over the same domain with `relation`.
+    fn with_relation(&self, relation: Relation) -> Self {
+        Hesitant {
+            domain: self.domain.clone(),
+            relation,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Checks that `other` is defined over the same domain.
+    fn check_same_domain<'a>(&'a self, other: &'a Self) -> Result<(), HesitantError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        if *self.domain == *other.domain {
+            Ok(())
+        } else {
+            Err(HesitantError::DomainMismatch {
+                expected: &self.domain,
+                found: &other.domain,
+            })
+        }
+    }
+
+    /// Borrowed view of the valuation.
+    ///
+    /// Gives access to the API only available for borrowed domains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let relation = HesitantRelation::AtLeast { domain: &domain, index: 1 };
+    /// let valuation = Hesitant::new_shared(&domain, relation).unwrap();
+    /// assert_eq!(
+    ///     valuation.as_borrowed(),
+    ///     Hesitant::new(HesitantRelation::AtLeast { domain: &domain, index: 1 }).unwrap()
+    /// );
+    /// ```
+    pub fn as_borrowed(&self) -> Hesitant<'_, T> {
+        Hesitant {
+            domain: &self.domain,
+            relation: self.relation,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'domain, H: DomainHandle<Qualitative<Trapezoidal>>> Hesitant<'domain, Trapezoidal, H> {
+    /// Returns the linguistic interval envelope `[s_i, s_j]` as a trapezoidal membership.
+    ///
+    /// The envelope goes from the beginning of the first covered label (`s_i`) to the end of the
+    /// last one (`s_j`), being fully compatible with all the labels between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::fuzzy::membership::Trapezoidal;
+    /// # use assessment::fuzzy::membership::piecewise::PiecewiseLinearFunction;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = Hesitant::new(HesitantRelation::Between { domain: &domain, lower: 1, upper: 3 }).unwrap();
+    ///
+    /// let envelope = valuation.interval_envelope();
+    /// assert_eq!(envelope, Trapezoidal::new(vec![0.0, 0.25, 0.75, 1.0]).unwrap());
+    /// assert_eq!(envelope.centroid(), 0.5);
+    /// assert_eq!(PiecewiseLinearFunction::from(&envelope).pieces().count(), 3);
+    /// ```
+    pub fn interval_envelope(&self) -> Trapezoidal {
+        let labels = self.covered_labels();
+        let first = labels[0].membership();
+        let last = labels[labels.len() - 1].membership();
+        Hesitant::<Trapezoidal>::envelope(labels, first.center().0, last.center().1)
+    }
+
+    /// Returns the fuzzy envelope of the valuation as a trapezoidal membership.
+    ///
+    /// Envelope support is the one of the linguistic interval (see [Hesitant::interval_envelope]).
+    /// Its core is obtained aggregating, with OWA operators, the cores of the covered labels: the
+    /// first half of them (sorted in descending order) gives the lower core limit and the second
+    /// half (sorted in ascending order) gives the upper one. Both use the exponential weights
+    /// `W = (α, α(1-α), α(1-α)², ..., (1-α)ⁿ⁻¹)`, so `alpha == 0` gives the linguistic interval
+    /// and `alpha == 1` the narrowest envelope.
+    ///
+    /// # Arguments
+    /// * `alpha`: OWA weights parameter, in range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantRelation};
+    /// # use assessment::fuzzy::membership::Trapezoidal;
+    /// # use assessment::fuzzy::membership::piecewise::PiecewiseLinearFunction;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = Hesitant::new(HesitantRelation::AtLeast { domain: &domain, index: 2 }).unwrap();
+    ///
+    /// for (alpha, limits) in [
+    ///     (0.0, vec![0.25, 0.5, 1.0, 1.0]),
+    ///     (0.5, vec![0.25, 0.625, 0.875, 1.0]),
+    ///     (1.0, vec![0.25, 0.75, 0.75, 1.0]),
+    /// ] {
+    ///     assert_eq!(valuation.fuzzy_envelope(alpha), Ok(Trapezoidal::new(limits).unwrap()));
+    /// }
+    ///
+    /// let envelope = valuation.fuzzy_envelope(0.5).unwrap();
+    /// assert!((envelope.centroid() - 0.677).abs() < 0.001);
+    /// assert_eq!(PiecewiseLinearFunction::from(&envelope).pieces().count(), 3);
+    ///
+    /// let single = Hesitant::new(HesitantRelation::SingleValue { domain: &domain, index: 1 }).unwrap();
+    /// assert_eq!(single.fuzzy_envelope(0.3).unwrap(), *domain.get_label_by_index(1).unwrap().membership());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::InvalidOwaParameter**: If `alpha` isn't in range `[0, 1]`.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Hesitant, HesitantError, HesitantRelation};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let valuation = Hesitant::new(HesitantRelation::AtMost { domain: &domain, index: 1 }).unwrap();
+    ///
+    /// for alpha in [-0.1, 1.1, f32::NAN] {
+    ///     assert!(matches!(
+    ///         valuation.fuzzy_envelope(alpha),
+    ///         Err(HesitantError::InvalidOwaParameter { .. })
+    ///     ));
+    /// }
+    /// ```
+    pub fn fuzzy_envelope(
+        &self,
+        alpha: f32,
+    ) -> Result<Trapezoidal, HesitantError<'_, Trapezoidal>> {
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(HesitantError::InvalidOwaParameter { alpha });
+        }
+        let labels = self.covered_labels();
+        let half = labels.len().div_ceil(2);
+        let mut lower: Vec<f32> = labels[..half]
+            .iter()
+            .map(|label| label.membership().center().0)
+            .collect();
+        let mut upper: Vec<f32> = labels[labels.len() - half..]
+            .iter()
+            .map(|label| label.membership().center().1)
+            .collect();
+        lower.sort_by(|x, y| y.total_cmp(x));
+        upper.sort_by(|x, y| x.total_cmp(y));
+        Ok(Hesitant::<Trapezoidal>::envelope(
+            labels,
+            exponential_owa(&lower, alpha),
+            exponential_owa(&upper, alpha),
+        ))
+    }
+
+    /// Compares `self` with `other` with a total order.
+    ///
+    /// Valuations are sorted by the centroid of the envelope of their linguistic intervals (see
+    /// [Hesitant::interval_envelope]), so the actual labels' semantics are taken into account.
+    /// On ties, the one covering less labels (i.e., the less hesitant) is the greatest, and then
+    /// the one with the greatest lower label. Valuations covering the same labels with different
+    /// relations are sorted by relation type, in declaration order, to be consistent with
+    /// equality.
+    ///
+    /// # Arguments
+    /// * `other`: Hesitant valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Hesitant;
+    /// # use std::cmp::Ordering;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |text| Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///
+    /// for (h1, h2, ordering) in [
+    ///     ("between b and d", "between a and c", Ordering::Greater),
+    ///     ("c", "between b and d", Ordering::Greater),
+    ///     ("at most b", "lower than d", Ordering::Less),
+    ///     ("at least c", "at least c", Ordering::Equal),
+    ///     ("at least c", "between c and e", Ordering::Less),
+    /// ] {
+    ///     assert_eq!(valuation(h1).compare(&valuation(h2)), Ok(ordering));
+    /// }
+    ///
+    /// let mut alternatives = vec![valuation("at least b"), valuation("c"), valuation("at most b")];
+    /// alternatives.sort_by(|a, b| b.compare(a).unwrap());
+    /// assert_eq!(
+    ///     alternatives.iter().map(|h| h.to_string()).collect::<Vec<_>>(),
+    ///     vec!["at least b", "c", "at most b"]
+    /// );
+    /// ```
+    ///
+    /// In non-uniform domains, intervals with the same central label may have different
+    /// centres:
+    ///
+    /// ```
     /// # use assessment::qualitative_domain;
+    /// # use assessment::valuation::Hesitant;
+    /// # use std::cmp::Ordering;
     /// let domain = qualitative_domain![
-    ///     "a" => vec![0.0, 0.0, 1.0],
-    ///     "b" => vec![0.0, 1.0, 1.0]
-    /// ].unwrap();
+    ///     "a" => vec![0.0, 0.0, 0.1],
+    ///     "b" => vec![0.0, 0.1, 0.2],
+    ///     "c" => vec![0.1, 0.2, 1.0],
+    ///     "d" => vec![0.2, 1.0, 1.0]
+    /// ]
+    /// .unwrap();
+    /// let valuation = |text| Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///
+    /// assert_eq!(valuation("between a and c").compare(&valuation("b")), Ok(Ordering::Greater));
+    /// assert_eq!(valuation("at most b").compare(&valuation("b")), Ok(Ordering::Less));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **HesitantError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, HesitantError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
     ///
     /// assert_eq!(
-    ///     *Hesitant::new(HesitantRelation::SingleValue { domain: &domain, index: 0 })
+    ///     Hesitant::parse_in_domain(&domain, "b")
     ///         .unwrap()
-    ///         .domain(),
-    ///     domain
+    ///         .compare(&Hesitant::parse_in_domain(&other, "b").unwrap()),
+    ///     Err(HesitantError::DomainMismatch { expected: &domain, found: &other })
     /// );
     /// ```
-    pub fn domain(&self) -> &'domain Qualitative<T> {
-        self.relation.domain()
+    pub fn compare<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> Result<Ordering, HesitantError<'a, Trapezoidal>> {
+        self.check_same_domain(other)?;
+        let (l1, u1) = self.indexes();
+        let (l2, u2) = other.indexes();
+        Ok(self
+            .centre()
+            .total_cmp(&other.centre())
+            .then((u2 - l2).cmp(&(u1 - l1)))
+            .then(l1.cmp(&l2))
+            .then(self.relation.cmp(&other.relation)))
+    }
+
+    /// Centroid of the linguistic interval envelope, rounded to 5 decimals so that intervals
+    /// with the same centre aren't told apart by rounding errors.
+    fn centre(&self) -> f32 {
+        utilities::math::round_f32(self.interval_envelope().centroid(), 5)
+    }
+
+    /// Labels covered by the valuation, sorted by index.
+    fn covered_labels(&self) -> Vec<&Label<Trapezoidal>> {
+        let (lower, upper) = self.indexes();
+        (lower..=upper)
+            .map(|index| self.domain.get_label_by_index(index).unwrap())
+            .collect()
     }
+
+    /// Trapezoidal membership covering `labels` with core `[b, c]`.
+    fn envelope(labels: Vec<&Label<Trapezoidal>>, b: f32, c: f32) -> Trapezoidal {
+        let (mut a, mut d) = labels[0].membership().coverage();
+        for label in &labels {
+            let (inf, sup) = label.membership().coverage();
+            a = a.min(inf);
+            d = d.max(sup);
+        }
+        let mut limits = vec![a, b, c, d];
+        limits.sort_by(|x, y| x.total_cmp(y));
+        Trapezoidal::new(limits).unwrap()
+    }
+}
+
+/// OWA aggregation of `values`, already sorted, with exponential weights
+/// `(α, α(1-α), α(1-α)², ..., (1-α)ⁿ⁻¹)`.
+fn exponential_owa(values: &[f32], alpha: f32) -> f32 {
+    let last = values.len() - 1;
+    values
+        .iter()
+        .enumerate()
+        .map(|(k, value)| {
+            let weight = (1.0 - alpha).powi(k as i32);
+            if k == last {
+                weight * value
+            } else {
+                alpha * weight * value
+            }
+        })
+        .sum()
 }
@@ -1,8 +1,13 @@
+use crate::domain::quantitative::NORMALIZATION_DOMAIN;
+use crate::utilities;
+use crate::valuation::Interval;
 use crate::Valuation;
 
-pub use hesitant::{Hesitant, HesitantError, HesitantRelation};
-pub use single::{Single, SingleError};
-pub use two_tuple::{TwoTuple, TwoTupleError};
+pub use hesitant::{
+    Hesitant, HesitantError, HesitantRelation, HesitantTransformPolicy, SharedHesitant,
+};
+pub use single::{SharedSingle, Single, SingleError};
+pub use two_tuple::{SharedTwoTuple, TwoTuple, TwoTupleError};
 
 /// Single linguistic valuations.
 pub mod single;
@@ -14,6 +19,42 @@ pub mod two_tuple;
 pub mod hesitant;
 
 /// Linguistic valuations.
-pub trait Linguistic {}
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Hesitant, HesitantRelation, Linguistic, Single, TwoTuple};
+/// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+///
+/// let valuations: Vec<Box<dyn Linguistic>> = vec![
+///     Box::new(Single::new_by_label_name(&domain, "b").unwrap()),
+///     Box::new(TwoTuple::new_by_label_name(&domain, "c", 0.25).unwrap()),
+///     Box::new(Hesitant::new(HesitantRelation::AtLeast { domain: &domain, index: 3 }).unwrap()),
+/// ];
+///
+/// for (valuation, range) in valuations.iter().zip([(1.0, 1.0), (2.25, 2.25), (3.0, 4.0)]) {
+///     assert_eq!(valuation.cardinality(), 5);
+///     assert_eq!(valuation.symbolic_range(), range);
+/// }
+/// ```
+pub trait Linguistic: Valuation {
+    /// Returns the cardinality of the valuation domain.
+    fn cardinality(&self) -> usize;
 
-impl Valuation for dyn Linguistic {}
+    /// Returns the symbolic range `(lower, upper)` covered by the valuation in its domain.
+    ///
+    /// Symbolic values go from `0.0` (first label) to `cardinality - 1` (last label).
+    fn symbolic_range(&self) -> (f32, f32);
+}
+
+/// Normalizes the symbolic range of a linguistic valuation in domain 0.0 to 1.0.
+pub(crate) fn normalize_symbolic_range<L: Linguistic + ?Sized>(
+    valuation: &L,
+) -> Interval<'static, f64> {
+    let granularity = valuation.cardinality().saturating_sub(1).max(1) as f64;
+    let (lower, upper) = valuation.symbolic_range();
+    let normalize =
+        |v: f32| (utilities::math::round_f64(v as f64, 5) / granularity).clamp(0.0, 1.0);
+    Interval::new(&NORMALIZATION_DOMAIN, normalize(lower), normalize(upper)).unwrap()
+}
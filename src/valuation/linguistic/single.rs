@@ -1,16 +1,31 @@
-use crate::domain::Qualitative;
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::label::standardize_name;
+use crate::fuzzy::membership::Trapezoidal;
 use crate::fuzzy::{Label, LabelMembership};
-use crate::valuation::Linguistic;
+use crate::valuation::{Interval, Linguistic, TwoTuple, Unified, UnifiedError, ValuationKind};
 use crate::Valuation;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Single linguistic valuations
+///
+/// The domain is borrowed by default. See [SharedSingle] for valuations owning their domain.
 #[derive(Debug, PartialEq)]
-pub struct Single<'domain, T: LabelMembership> {
-    domain: &'domain Qualitative<T>,
+pub struct Single<
+    'domain,
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>> = &'domain Qualitative<T>,
+> {
+    domain: H,
     index: usize,
+    lifetime: PhantomData<&'domain Qualitative<T>>,
 }
 
+/// Single linguistic valuation sharing the ownership of its domain.
+pub type SharedSingle<T> = Single<'static, T, Arc<Qualitative<T>>>;
+
 /// Single errors types.
 #[derive(Debug, PartialEq)]
 pub enum SingleError<'domain, T: LabelMembership> {
@@ -24,6 +39,11 @@ pub enum SingleError<'domain, T: LabelMembership> {
         domain: &'domain Qualitative<T>,
         name: String,
     },
+    /// Different domains where the same one was expected.
+    DomainMismatch {
+        expected: &'domain Qualitative<T>,
+        found: &'domain Qualitative<T>,
+    },
 }
 
 // Note: + Display added because clion doesn't detect here correctly the trait_alias feature
@@ -47,12 +67,89 @@ impl<'domain, T: LabelMembership> Display for SingleError<'domain, T> {
                     domain.get_labels_names()
                 )
             }
+            DomainMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Domain {} differs from expected domain {}.",
+                    found, expected
+                )
+            }
         }
     }
 }
 
-impl<'domain, T: LabelMembership> Linguistic for Single<'domain, T> {}
-impl<'domain, T: LabelMembership> Valuation for Single<'domain, T> {}
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Display
+    for Single<'domain, T, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label().name())
+    }
+}
+
+/// Single valuations are ordered when defined over the same domain (see [Single::compare]).
+impl<'domain, T, H> PartialOrd for Single<'domain, T, H>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>> + PartialEq,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare(other).ok()
+    }
+}
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Linguistic
+    for Single<'domain, T, H>
+{
+    fn cardinality(&self) -> usize {
+        self.domain.cardinality()
+    }
+
+    fn symbolic_range(&self) -> (f32, f32) {
+        (self.index as f32, self.index as f32)
+    }
+}
+
+/// Single valuations as generic valuations.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Single, ValuationKind};
+/// # use assessment::Valuation;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuation: Box<dyn Valuation> = Box::new(Single::new_by_label_name(&domain, "a").unwrap());
+///
+/// assert_eq!(valuation.kind(), ValuationKind::Single);
+/// assert_eq!(format!("{}", valuation), "a");
+/// assert_eq!(valuation.normalization().value(), (0.0, 0.0));
+/// assert_eq!(format!("{}", valuation.negation()), "c");
+///
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// assert_eq!(*valuation.unification(&blts).unwrap().measures(), vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+/// ```
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Valuation
+    for Single<'domain, T, H>
+{
+    fn kind(&self) -> ValuationKind {
+        ValuationKind::Single
+    }
+
+    fn normalization(&self) -> Interval<'static, f64> {
+        super::normalize_symbolic_range(self)
+    }
+
+    fn negation(&self) -> Box<dyn Valuation + '_> {
+        Box::new(self.neg())
+    }
+
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        self.as_borrowed().unification_in_domain(domain)
+    }
+}
 
 impl<'domain, T: LabelMembership> Single<'domain, T> {
     /// Creates a new valuation given label `index` in `domain`.
@@ -99,7 +196,11 @@ impl<'domain, T: LabelMembership> Single<'domain, T> {
         if index >= domain.cardinality() {
             Err(InvalidIndex { domain, index })
         } else {
-            Ok(Self { domain, index })
+            Ok(Self {
+                domain,
+                index,
+                lifetime: PhantomData,
+            })
         }
     }
 
@@ -147,7 +248,11 @@ impl<'domain, T: LabelMembership> Single<'domain, T> {
     ) -> Result<Self, SingleError<'domain, T>> {
         use SingleError::*;
         if let Some(index) = domain.label_index(name) {
-            Ok(Self { domain, index })
+            Ok(Self {
+                domain,
+                index,
+                lifetime: PhantomData,
+            })
         } else {
             Err(InvalidName {
                 domain,
@@ -156,6 +261,158 @@ impl<'domain, T: LabelMembership> Single<'domain, T> {
         }
     }
 
+    /// Parses a valuation in `domain` from `text`, such as `medium`.
+    ///
+    /// Label name is standardized before looking for it in `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain reference.
+    /// * `text`: Valuation text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// assert_eq!(Single::parse_in_domain(&domain, " Medium "), Single::new_by_label_index(&domain, 1));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **SingleError::InvalidName**: If `text` doesn't name a label of `domain`.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Single, SingleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Single::parse_in_domain(&domain, "Very high"),
+    ///     Err(SingleError::InvalidName { domain: &domain, name: String::from("very high") })
+    /// );
+    /// ```
+    ///
+    /// See also [Single::new_by_label_name].
+    pub fn parse_in_domain(
+        domain: &'domain Qualitative<T>,
+        text: &str,
+    ) -> Result<Self, SingleError<'domain, T>> {
+        Single::new_by_label_name(domain, &standardize_name(text))
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_domain;
+    /// # use assessment::Valuation;
+    /// let domain = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 1.0],
+    ///     "b" => vec![0.0, 1.0, 1.0]
+    /// ].unwrap();
+    ///
+    /// assert_eq!(*Single::new_by_label_index(&domain, 0).unwrap().domain(), domain);
+    /// ```
+    pub fn domain(&self) -> &'domain Qualitative<T> {
+        self.domain
+    }
+}
+
+impl<T: LabelMembership> SharedSingle<T> {
+    /// Creates a new valuation given label `index` in a shared `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `index`: Label index in `domain`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{SharedSingle, Single};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let valuation: SharedSingle<_> = {
+    ///     let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    ///     Single::new_shared_by_label_index(&domain, 0).unwrap()
+    /// };
+    ///
+    /// let handle = std::thread::spawn(move || valuation.neg().to_string());
+    /// assert_eq!(handle.join().unwrap(), "c");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [Single::new_by_label_index].
+    pub fn new_shared_by_label_index(
+        domain: &Arc<Qualitative<T>>,
+        index: usize,
+    ) -> Result<Self, SingleError<'_, T>> {
+        let valuation = Single::new_by_label_index(domain, index)?;
+        Ok(Self {
+            domain: Arc::clone(domain),
+            index: valuation.index,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Creates a new valuation given label `name` of a label in a shared `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `name`: Label `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Single, SingleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    ///
+    /// assert_eq!(Single::new_shared_by_label_name(&domain, "b").unwrap().index(), 1);
+    /// assert_eq!(
+    ///     Single::new_shared_by_label_name(&domain, "d"),
+    ///     Err(SingleError::InvalidName { domain: &domain, name: String::from("d") })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [Single::new_by_label_name].
+    pub fn new_shared_by_label_name<'a>(
+        domain: &'a Arc<Qualitative<T>>,
+        name: &str,
+    ) -> Result<Self, SingleError<'a, T>> {
+        let valuation = Single::new_by_label_name(domain, name)?;
+        Ok(Self {
+            domain: Arc::clone(domain),
+            index: valuation.index,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let valuation = Single::new_shared_by_label_index(&domain, 0).unwrap();
+    /// assert_eq!(valuation.domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Qualitative<T> {
+        &self.domain
+    }
+}
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Single<'domain, T, H> {
     /// Returns associated valuation index in domain.
     ///
     /// # Examples
@@ -206,25 +463,6 @@ impl<'domain, T: LabelMembership> Single<'domain, T> {
         self.domain.get_label_by_index(self.index).unwrap()
     }
 
-    /// Returns valuation domain.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use assessment::valuation::Single;
-    /// # use assessment::qualitative_domain;
-    /// # use assessment::Valuation;
-    /// let domain = qualitative_domain![
-    ///     "a" => vec![0.0, 0.0, 1.0],
-    ///     "b" => vec![0.0, 1.0, 1.0]
-    /// ].unwrap();
-    ///
-    /// assert_eq!(*Single::new_by_label_index(&domain, 0).unwrap().domain(), domain);
-    /// ```
-    pub fn domain(&self) -> &'domain Qualitative<T> {
-        self.domain
-    }
-
     /// Valuation negation.
     ///
     /// # Examples
@@ -249,8 +487,284 @@ impl<'domain, T: LabelMembership> Single<'domain, T> {
     /// ```
     pub fn neg(&self) -> Self {
         Self {
-            domain: self.domain,
+            domain: self.domain.clone(),
             index: self.domain.cardinality() - self.index - 1,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Compares `self` with `other`, defined over the same domain.
+    ///
+    /// Valuations are compared by label index.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::cmp::Ordering;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |index| Single::new_by_label_index(&domain, index).unwrap();
+    ///
+    /// for (i1, i2, ordering) in [
+    ///     (1, 2, Ordering::Less),
+    ///     (3, 1, Ordering::Greater),
+    ///     (3, 3, Ordering::Equal),
+    /// ] {
+    ///     assert_eq!(valuation(i1).compare(&valuation(i2)), Ok(ordering));
+    ///     assert_eq!(valuation(i1).partial_cmp(&valuation(i2)), Some(ordering));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **SingleError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Single, SingleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Single::new_by_label_index(&domain, 1)
+    ///         .unwrap()
+    ///         .compare(&Single::new_by_label_index(&other, 1).unwrap()),
+    ///     Err(SingleError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn compare<'a>(&'a self, other: &'a Self) -> Result<Ordering, SingleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        Ok(self.index.cmp(&other.index))
+    }
+
+    /// Returns the minimum of `self` and `other`, or `self` if they are equal.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let low = Single::new_by_label_index(&domain, 1).unwrap();
+    /// let high = Single::new_by_label_index(&domain, 2).unwrap();
+    ///
+    /// assert_eq!(low.min(&high), Ok(&low));
+    /// assert_eq!(high.min(&low), Ok(&low));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **SingleError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn min<'a>(&'a self, other: &'a Self) -> Result<&'a Self, SingleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        Ok(match self.compare(other)? {
+            Ordering::Greater => other,
+            _ => self,
+        })
+    }
+
+    /// Returns the maximum of `self` and `other`, or `other` if they are equal.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let low = Single::new_by_label_index(&domain, 1).unwrap();
+    /// let high = Single::new_by_label_index(&domain, 2).unwrap();
+    ///
+    /// assert_eq!(low.max(&high), Ok(&high));
+    /// assert_eq!(high.max(&low), Ok(&high));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **SingleError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn max<'a>(&'a self, other: &'a Self) -> Result<&'a Self, SingleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        Ok(match self.compare(other)? {
+            Ordering::Greater => self,
+            _ => other,
+        })
+    }
+
+    /// Restricts the valuation to the range `[min, max]`.
+    ///
+    /// # Arguments
+    /// * `min`: Lower bound, over the same domain.
+    /// * `max`: Upper bound, over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let low = Single::new_by_label_index(&domain, 1).unwrap();
+    /// let high = Single::new_by_label_index(&domain, 2).unwrap();
+    /// let higher = Single::new_by_label_index(&domain, 3).unwrap();
+    ///
+    /// assert_eq!(low.clamp(&high, &higher), Ok(&high));
+    /// assert_eq!(high.clamp(&low, &higher), Ok(&high));
+    /// assert_eq!(higher.clamp(&low, &high), Ok(&high));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **SingleError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// # Panics
+    ///
+    /// If `min > max`.
+    pub fn clamp<'a>(&'a self, min: &'a Self, max: &'a Self) -> Result<&'a Self, SingleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        assert!(min.compare(max)? != Ordering::Greater, "min > max in clamp");
+        Ok(if self.compare(min)? == Ordering::Less {
+            min
+        } else if self.compare(max)? == Ordering::Greater {
+            max
+        } else {
+            self
+        })
+    }
+
+    /// Sorts `valuations` in ascending order (see [Single::compare]).
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Single;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let mut valuations = vec![
+    ///     Single::new_by_label_index(&domain, 3).unwrap(),
+    ///     Single::new_by_label_index(&domain, 1).unwrap(),
+    ///     Single::new_by_label_index(&domain, 2).unwrap(),
+    /// ];
+    ///
+    /// Single::sort(&mut valuations).unwrap();
+    /// assert_eq!(
+    ///     valuations.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+    ///     vec!["b", "c", "d"]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **SingleError::DomainMismatch**: If valuations are defined over different domains, in which
+    /// case `valuations` are left untouched.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Single, SingleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let mut valuations = vec![
+    ///     Single::new_by_label_index(&domain, 1).unwrap(),
+    ///     Single::new_by_label_index(&other, 1).unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Single::sort(&mut valuations),
+    ///     Err(SingleError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn sort<'a>(valuations: &'a mut [Self]) -> Result<(), SingleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        if let Some(position) = valuations
+            .iter()
+            .position(|valuation| *valuation.domain != *valuations[0].domain)
+        {
+            let valuations: &'a [Self] = valuations;
+            return Err(SingleError::DomainMismatch {
+                expected: &valuations[0].domain,
+                found: &valuations[position].domain,
+            });
+        }
+        valuations.sort_by_key(|valuation| valuation.index);
+        Ok(())
+    }
+
+    /// Checks that `other` is defined over the same domain.
+    pub(crate) fn check_same_domain<'a>(&'a self, other: &'a Self) -> Result<(), SingleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        if *self.domain == *other.domain {
+            Ok(())
+        } else {
+            Err(SingleError::DomainMismatch {
+                expected: &self.domain,
+                found: &other.domain,
+            })
+        }
+    }
+
+    /// Valuation over the same domain given its label `index`.
+    pub(crate) fn with_index(&self, index: usize) -> Self {
+        Self::label_in(self.domain.clone(), index)
+    }
+
+    /// Valuation over `domain` given its label `index`, which must be in domain range.
+    pub(crate) fn label_in(domain: H, index: usize) -> Self {
+        Self {
+            domain,
+            index,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// 2-tuple valuation over the same domain given its symbolic value (Δ), which must be in
+    /// domain range.
+    pub(crate) fn delta_in_domain(&self, beta: f32) -> TwoTuple<'domain, T, H> {
+        TwoTuple::delta_in(self.domain.clone(), beta)
+    }
+
+    /// Borrowed view of the valuation.
+    ///
+    /// Gives access to the API only available for borrowed domains (unification, conversions...).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Single, TwoTuple};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let valuation = Single::new_shared_by_label_index(&domain, 1).unwrap();
+    /// assert_eq!(valuation.as_borrowed(), Single::new_by_label_index(&domain, 1).unwrap());
+    /// assert_eq!(TwoTuple::try_from(&valuation.as_borrowed()).unwrap().to_string(), "(b, 0.00)");
+    /// ```
+    pub fn as_borrowed(&self) -> Single<'_, T> {
+        Single {
+            domain: &self.domain,
+            index: self.index,
+            lifetime: PhantomData,
         }
     }
 }
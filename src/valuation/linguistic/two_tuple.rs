@@ -1,18 +1,35 @@
-use crate::domain::Qualitative;
+use crate::domain::{DomainHandle, Qualitative};
+use crate::fuzzy::label::standardize_name;
+use crate::fuzzy::membership::Trapezoidal;
 use crate::fuzzy::{Label, LabelMembership};
 use crate::utilities;
-use crate::valuation::Linguistic;
+use crate::utilities::parser::{ParseError, Parser};
+use crate::valuation::{Interval, Linguistic, Unified, UnifiedError, ValuationKind};
 use crate::Valuation;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops;
+use std::sync::Arc;
 
 /// TwoTuple linguistic valuations.
+///
+/// The domain is borrowed by default. See [SharedTwoTuple] for valuations owning their domain.
 #[derive(Debug, PartialEq)]
-pub struct TwoTuple<'domain, T: LabelMembership> {
-    domain: &'domain Qualitative<T>,
+pub struct TwoTuple<
+    'domain,
+    T: LabelMembership,
+    H: DomainHandle<Qualitative<T>> = &'domain Qualitative<T>,
+> {
+    domain: H,
     index: usize,
     alpha: f32,
+    lifetime: PhantomData<&'domain Qualitative<T>>,
 }
 
+/// TwoTuple linguistic valuation sharing the ownership of its domain.
+pub type SharedTwoTuple<T> = TwoTuple<'static, T, Arc<Qualitative<T>>>;
+
 /// TwoTuple errors types.
 #[derive(Debug, PartialEq)]
 pub enum TwoTupleError<'domain, T: LabelMembership> {
@@ -32,6 +49,18 @@ pub enum TwoTupleError<'domain, T: LabelMembership> {
     InvalidSymbolicTranslationOnFirstLabel { alpha: f32 },
     /// Invalid symbolic translation on last label.
     InvalidSymbolicTranslationOnLastLabel { alpha: f32 },
+    /// Different domains where the same one was expected.
+    DomainMismatch {
+        expected: &'domain Qualitative<T>,
+        found: &'domain Qualitative<T>,
+    },
+    /// Symbolic value out of domain range.
+    Overflow {
+        domain: &'domain Qualitative<T>,
+        beta: f32,
+    },
+    /// Malformed valuation text.
+    InvalidSyntax { error: ParseError },
 }
 
 // Note: + Display added because clion doesn't detect here correctly the trait_alias feature
@@ -76,12 +105,101 @@ impl<'domain, T: LabelMembership> Display for TwoTupleError<'domain, T> {
                     alpha
                 )
             }
+            DomainMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Domain {} differs from expected domain {}.",
+                    found, expected
+                )
+            }
+            Overflow { domain, beta } => {
+                write!(
+                    f,
+                    "Symbolic value {} out of domain range [0, {}].",
+                    beta,
+                    domain.cardinality() - 1
+                )
+            }
+            InvalidSyntax { error } => {
+                write!(f, "Invalid syntax: {}", error)
+            }
         }
     }
 }
 
-impl<'domain, T: LabelMembership> Linguistic for TwoTuple<'domain, T> {}
-impl<'domain, T: LabelMembership> Valuation for TwoTuple<'domain, T> {}
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Display
+    for TwoTuple<'domain, T, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {:.2})", self.label().name(), self.alpha)
+    }
+}
+
+/// TwoTuple valuations are ordered when defined over the same domain (see [TwoTuple::compare]).
+impl<'domain, T, H> PartialOrd for TwoTuple<'domain, T, H>
+where
+    T: LabelMembership + PartialEq,
+    H: DomainHandle<Qualitative<T>> + PartialEq,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare(other).ok()
+    }
+}
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Linguistic
+    for TwoTuple<'domain, T, H>
+{
+    fn cardinality(&self) -> usize {
+        self.domain.cardinality()
+    }
+
+    fn symbolic_range(&self) -> (f32, f32) {
+        (self.inverse_delta(), self.inverse_delta())
+    }
+}
+
+/// TwoTuple valuations as generic valuations.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{TwoTuple, ValuationKind};
+/// # use assessment::Valuation;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuation: Box<dyn Valuation> = Box::new(TwoTuple::new_by_label_name(&domain, "b", 0.3).unwrap());
+///
+/// assert_eq!(valuation.kind(), ValuationKind::TwoTuple);
+/// assert_eq!(format!("{}", valuation), "(b, 0.30)");
+/// assert_eq!(valuation.normalization().value(), (0.65, 0.65));
+/// assert_eq!(format!("{}", valuation.negation()), "(b, -0.30)");
+///
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// let measures = valuation.unification(&blts).unwrap().measures().clone();
+/// assert_eq!(measures, vec![0.0, 0.0, 0.4, 0.6, 0.0]);
+/// ```
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> Valuation
+    for TwoTuple<'domain, T, H>
+{
+    fn kind(&self) -> ValuationKind {
+        ValuationKind::TwoTuple
+    }
+
+    fn normalization(&self) -> Interval<'static, f64> {
+        super::normalize_symbolic_range(self)
+    }
+
+    fn negation(&self) -> Box<dyn Valuation + '_> {
+        Box::new(self.neg())
+    }
+
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        self.as_borrowed().unification_in_domain(domain)
+    }
+}
 
 impl<'domain, T: LabelMembership> TwoTuple<'domain, T> {
     /// Creates a new valuation given label `index` in `domain` and symbolic translation value.
@@ -196,6 +314,7 @@ impl<'domain, T: LabelMembership> TwoTuple<'domain, T> {
                 domain,
                 index,
                 alpha,
+                lifetime: PhantomData,
             })
         }
     }
@@ -310,79 +429,6 @@ impl<'domain, T: LabelMembership> TwoTuple<'domain, T> {
         }
     }
 
-    /// Returns associated valuation index in domain.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use assessment::valuation::TwoTuple;
-    /// # use assessment::qualitative_domain;
-    /// let domain = qualitative_domain![
-    ///     "a" => vec![0.0, 0.0, 1.0],
-    ///     "b" => vec![0.0, 1.0, 1.0]
-    /// ].unwrap();
-    ///
-    /// for (e, v) in [
-    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.0), 0),
-    ///     (TwoTuple::new_by_label_index(&domain, 1, 0.0), 1),
-    ///     (TwoTuple::new_by_label_name(&domain, "a", 0.0), 0),
-    ///     (TwoTuple::new_by_label_name(&domain, "b", 0.0), 1)
-    /// ] {
-    ///     assert_eq!(e.unwrap().index(), v);
-    /// }
-    /// ```
-    pub fn index(&self) -> usize {
-        self.index
-    }
-
-    /// Returns associated valuation label in domain.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use assessment::valuation::TwoTuple;
-    /// # use assessment::qualitative_domain;
-    /// let domain = qualitative_domain![
-    ///     "a" => vec![0.0, 0.0, 1.0],
-    ///     "b" => vec![0.0, 1.0, 1.0]
-    /// ].unwrap();
-    ///
-    /// for (e, v) in [
-    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.0), 0),
-    ///     (TwoTuple::new_by_label_index(&domain, 1, 0.0), 1),
-    ///     (TwoTuple::new_by_label_name(&domain, "a", 0.0), 0),
-    ///     (TwoTuple::new_by_label_name(&domain, "b", 0.0), 1)
-    /// ] {
-    ///     assert_eq!(e.unwrap().label(), domain.get_label_by_index(v).unwrap());
-    /// }
-    /// ```
-    pub fn label(&self) -> &Label<T> {
-        self.domain.get_label_by_index(self.index).unwrap()
-    }
-
-    /// Returns symbolic translation value.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use assessment::valuation::TwoTuple;
-    /// # use assessment::qualitative_domain;
-    /// let domain = qualitative_domain![
-    ///     "a" => vec![0.0, 0.0, 1.0],
-    ///     "b" => vec![0.0, 1.0, 1.0]
-    /// ].unwrap();
-    ///
-    /// for (e, v) in [
-    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.2), 0.2),
-    ///     (TwoTuple::new_by_label_index(&domain, 1, -0.23), -0.23)
-    /// ] {
-    ///     assert_eq!(e.unwrap().alpha(), v);
-    /// }
-    /// ```
-    pub fn alpha(&self) -> f32 {
-        self.alpha
-    }
-
     /// Creates a new TwoTuple from beta value.
     ///
     /// Delta (Δ) is defined by **Δ(β) = (s<sub>round(β)</sub>, β-round(β)) = (s<sub>i</sub>, α)**.
@@ -463,35 +509,85 @@ impl<'domain, T: LabelMembership> TwoTuple<'domain, T> {
     ///     Err(TwoTupleError::InvalidSymbolicTranslationOnLastLabel { alpha: 0.2 })
     /// );
     /// ```
-    pub fn delta(domain: &'domain Qualitative<T>, beta: f32) -> Result<Self, TwoTupleError<T>> {
+    pub fn delta(
+        domain: &'domain Qualitative<T>,
+        beta: f32,
+    ) -> Result<Self, TwoTupleError<'domain, T>> {
         let index = beta.round() as usize;
         let alpha = beta - index as f32;
         TwoTuple::new_by_label_index(domain, index, alpha)
     }
 
-    /// Returns inverse delta value.
+    /// Parses a valuation in `domain` from `text`, such as `(high, -0.23)`.
     ///
-    /// Inverse delta (Δ<sup>-1</sup>) is defined by **Δ<sup>-1</sup>(s<sub>i</sub>, α) = β = i + α**.
+    /// Label name is standardized before looking for it in `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain reference.
+    /// * `text`: Valuation text.
     ///
     /// # Examples
     ///
     /// ```
     /// # use assessment::valuation::TwoTuple;
-    /// # use assessment::qualitative_domain;
-    /// let domain = qualitative_domain![
-    ///     "a" => vec![0.0, 0.0, 1.0],
-    ///     "b" => vec![0.0, 1.0, 1.0]
-    /// ].unwrap();
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
     ///
-    /// for (e, v) in [
-    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.2), 0.2),
-    ///     (TwoTuple::new_by_label_index(&domain, 1, -0.23), 0.77)
-    /// ] {
-    ///     assert_eq!(e.unwrap().inverse_delta(), v);
-    /// }
+    /// assert_eq!(
+    ///     TwoTuple::parse_in_domain(&domain, "(High, -0.23)"),
+    ///     Ok(TwoTuple::new_by_label_index(&domain, 2, -0.23).unwrap())
+    /// );
     /// ```
-    pub fn inverse_delta(&self) -> f32 {
-        self.index as f32 + self.alpha
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::InvalidSyntax**: If `text` isn't a label name and a number between
+    /// parentheses.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::utilities::parser::ParseError;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     TwoTuple::parse_in_domain(&domain, "high, -0.23"),
+    ///     Err(TwoTupleError::InvalidSyntax {
+    ///         error: ParseError::UnexpectedCharacter {
+    ///             position: 0,
+    ///             expected: String::from("'('"),
+    ///             found: 'h'
+    ///         }
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// Other errors: see [TwoTuple::new_by_label_name].
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["low", "medium", "high"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     TwoTuple::parse_in_domain(&domain, "(good, 0.1)"),
+    ///     Err(TwoTupleError::InvalidName { domain: &domain, name: String::from("good") })
+    /// );
+    /// ```
+    pub fn parse_in_domain(
+        domain: &'domain Qualitative<T>,
+        text: &str,
+    ) -> Result<Self, TwoTupleError<'domain, T>> {
+        let (name, alpha) = Parser::parse_all(text, |parser| {
+            parser.expect('(')?;
+            let name = standardize_name(parser.until(",")?);
+            parser.expect(',')?;
+            let alpha = parser.number::<f32>()?;
+            parser.expect(')')?;
+            Ok((name, alpha))
+        })
+        .map_err(|error| TwoTupleError::InvalidSyntax { error })?;
+        TwoTuple::new_by_label_name(domain, &name, alpha)
     }
 
     /// Returns valuation domain.
@@ -512,38 +608,1096 @@ impl<'domain, T: LabelMembership> TwoTuple<'domain, T> {
     pub fn domain(&self) -> &'domain Qualitative<T> {
         self.domain
     }
+}
 
-    /// Valuation negation.
+impl<T: LabelMembership> SharedTwoTuple<T> {
+    /// Creates a new valuation given label `index` in a shared `domain` and symbolic translation
+    /// value.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `index`: Label index in `domain`.
+    /// * `alpha`: Symbolic translation.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::valuation::{SharedTwoTuple, TwoTuple};
     /// # use assessment::qualitative_symmetric_domain;
-    /// # use assessment::Valuation;
-    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
-    /// assert_eq!(
-    ///     TwoTuple::new_by_label_name(&domain, "b", 0.0).unwrap().neg(),
-    ///     TwoTuple::new_by_label_name(&domain, "d", 0.0).unwrap()
-    /// );
-    /// assert_eq!(
-    ///     TwoTuple::new_by_label_name(&domain, "a", 0.25).unwrap().neg(),
-    ///     TwoTuple::new_by_label_name(&domain, "e", -0.25).unwrap()
-    /// );
+    /// # use std::sync::Arc;
+    /// let valuation: SharedTwoTuple<_> = {
+    ///     let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    ///     TwoTuple::new_shared_by_label_index(&domain, 0, 0.25).unwrap()
+    /// };
+    ///
+    /// let handle = std::thread::spawn(move || valuation.neg().to_string());
+    /// assert_eq!(handle.join().unwrap(), "(c, -0.25)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [TwoTuple::new_by_label_index].
+    pub fn new_shared_by_label_index(
+        domain: &Arc<Qualitative<T>>,
+        index: usize,
+        alpha: f32,
+    ) -> Result<Self, TwoTupleError<'_, T>> {
+        let valuation = TwoTuple::new_by_label_index(domain, index, alpha)?;
+        Ok(Self::from_borrowed(domain, &valuation))
+    }
+
+    /// Creates a new valuation given label `name` of a label in a shared `domain` and symbolic
+    /// translation value.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `name`: Label `name`.
+    /// * `alpha`: Symbolic translation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    ///
+    /// assert_eq!(TwoTuple::new_shared_by_label_name(&domain, "b", -0.1).unwrap().inverse_delta(), 0.9);
     /// assert_eq!(
-    ///     TwoTuple::new_by_label_name(&domain, "c", -0.2).unwrap().neg(),
-    ///     TwoTuple::new_by_label_name(&domain, "c", 0.2).unwrap()
+    ///     TwoTuple::new_shared_by_label_name(&domain, "d", 0.0),
+    ///     Err(TwoTupleError::InvalidName { domain: &domain, name: String::from("d") })
     /// );
     /// ```
-    pub fn neg(&self) -> Self {
-        let beta = (self.domain.cardinality() - 1) as f32 - self.inverse_delta();
-        let index = beta.round() as usize;
-        let mut alpha = beta - index as f32;
-        alpha = utilities::math::round_f32(alpha, 5);
+    ///
+    /// # Errors
+    ///
+    /// Same as [TwoTuple::new_by_label_name].
+    pub fn new_shared_by_label_name<'a>(
+        domain: &'a Arc<Qualitative<T>>,
+        name: &str,
+        alpha: f32,
+    ) -> Result<Self, TwoTupleError<'a, T>> {
+        let valuation = TwoTuple::new_by_label_name(domain, name, alpha)?;
+        Ok(Self::from_borrowed(domain, &valuation))
+    }
+
+    /// Delta function in a shared `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `beta`: Value to transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// assert_eq!(TwoTuple::delta_shared(&domain, 1.7).unwrap().to_string(), "(c, -0.30)");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [TwoTuple::delta].
+    pub fn delta_shared(
+        domain: &Arc<Qualitative<T>>,
+        beta: f32,
+    ) -> Result<Self, TwoTupleError<'_, T>> {
+        let valuation = TwoTuple::delta(domain, beta)?;
+        Ok(Self::from_borrowed(domain, &valuation))
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let valuation = TwoTuple::new_shared_by_label_index(&domain, 0, 0.0).unwrap();
+    /// assert_eq!(valuation.domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Qualitative<T> {
+        &self.domain
+    }
+
+    fn from_borrowed(domain: &Arc<Qualitative<T>>, valuation: &TwoTuple<T>) -> Self {
         Self {
-            domain: self.domain,
-            index,
-            alpha,
+            domain: Arc::clone(domain),
+            index: valuation.index,
+            alpha: valuation.alpha,
+            lifetime: PhantomData,
         }
     }
 }
+
+impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> TwoTuple<'domain, T, H> {
+    /// Returns associated valuation index in domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_domain;
+    /// let domain = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 1.0],
+    ///     "b" => vec![0.0, 1.0, 1.0]
+    /// ].unwrap();
+    ///
+    /// for (e, v) in [
+    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.0), 0),
+    ///     (TwoTuple::new_by_label_index(&domain, 1, 0.0), 1),
+    ///     (TwoTuple::new_by_label_name(&domain, "a", 0.0), 0),
+    ///     (TwoTuple::new_by_label_name(&domain, "b", 0.0), 1)
+    /// ] {
+    ///     assert_eq!(e.unwrap().index(), v);
+    /// }
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns associated valuation label in domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_domain;
+    /// let domain = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 1.0],
+    ///     "b" => vec![0.0, 1.0, 1.0]
+    /// ].unwrap();
+    ///
+    /// for (e, v) in [
+    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.0), 0),
+    ///     (TwoTuple::new_by_label_index(&domain, 1, 0.0), 1),
+    ///     (TwoTuple::new_by_label_name(&domain, "a", 0.0), 0),
+    ///     (TwoTuple::new_by_label_name(&domain, "b", 0.0), 1)
+    /// ] {
+    ///     assert_eq!(e.unwrap().label(), domain.get_label_by_index(v).unwrap());
+    /// }
+    /// ```
+    pub fn label(&self) -> &Label<T> {
+        self.domain.get_label_by_index(self.index).unwrap()
+    }
+
+    /// Returns symbolic translation value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_domain;
+    /// let domain = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 1.0],
+    ///     "b" => vec![0.0, 1.0, 1.0]
+    /// ].unwrap();
+    ///
+    /// for (e, v) in [
+    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.2), 0.2),
+    ///     (TwoTuple::new_by_label_index(&domain, 1, -0.23), -0.23)
+    /// ] {
+    ///     assert_eq!(e.unwrap().alpha(), v);
+    /// }
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Returns inverse delta value.
+    ///
+    /// Inverse delta (Δ<sup>-1</sup>) is defined by **Δ<sup>-1</sup>(s<sub>i</sub>, α) = β = i + α**.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_domain;
+    /// let domain = qualitative_domain![
+    ///     "a" => vec![0.0, 0.0, 1.0],
+    ///     "b" => vec![0.0, 1.0, 1.0]
+    /// ].unwrap();
+    ///
+    /// for (e, v) in [
+    ///     (TwoTuple::new_by_label_index(&domain, 0, 0.2), 0.2),
+    ///     (TwoTuple::new_by_label_index(&domain, 1, -0.23), 0.77)
+    /// ] {
+    ///     assert_eq!(e.unwrap().inverse_delta(), v);
+    /// }
+    /// ```
+    pub fn inverse_delta(&self) -> f32 {
+        self.index as f32 + self.alpha
+    }
+
+    /// Valuation negation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::Valuation;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// assert_eq!(
+    ///     TwoTuple::new_by_label_name(&domain, "b", 0.0).unwrap().neg(),
+    ///     TwoTuple::new_by_label_name(&domain, "d", 0.0).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     TwoTuple::new_by_label_name(&domain, "a", 0.25).unwrap().neg(),
+    ///     TwoTuple::new_by_label_name(&domain, "e", -0.25).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     TwoTuple::new_by_label_name(&domain, "c", -0.2).unwrap().neg(),
+    ///     TwoTuple::new_by_label_name(&domain, "c", 0.2).unwrap()
+    /// );
+    /// ```
+    pub fn neg(&self) -> Self {
+        self.delta_in_domain((self.domain.cardinality() - 1) as f32 - self.inverse_delta())
+    }
+
+    /// Sum of valuations, saturated to the domain range.
+    ///
+    /// Computed as **Δ(min(Δ⁻¹(a) + Δ⁻¹(b), g))**, where `g` is the domain granularity. It is
+    /// also available as the `+` operator.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let b = TwoTuple::new_by_label_name(&domain, "b", 0.2).unwrap();
+    /// let c = TwoTuple::new_by_label_name(&domain, "c", 0.4).unwrap();
+    ///
+    /// assert_eq!(b.saturating_add(&b), TwoTuple::new_by_label_name(&domain, "c", 0.4).unwrap());
+    /// assert_eq!(b.saturating_add(&c), TwoTuple::new_by_label_name(&domain, "e", -0.4).unwrap());
+    /// assert_eq!(c.saturating_add(&c), TwoTuple::new_by_label_name(&domain, "e", 0.0).unwrap());
+    /// assert_eq!(&b + &c, b.saturating_add(&c));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If valuations are defined over different domains. See [TwoTuple::checked_add].
+    pub fn saturating_add(&self, other: &Self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.saturated(self.checked_add(other))
+    }
+
+    /// Sum of valuations.
+    ///
+    /// Computed as **Δ(Δ⁻¹(a) + Δ⁻¹(b))**.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let b = TwoTuple::new_by_label_name(&domain, "b", 0.2).unwrap();
+    /// let c = TwoTuple::new_by_label_name(&domain, "c", 0.4).unwrap();
+    ///
+    /// assert_eq!(b.checked_add(&c), TwoTuple::new_by_label_name(&domain, "e", -0.4));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.0)
+    ///         .unwrap()
+    ///         .checked_add(&TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap()),
+    ///     Err(TwoTupleError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    ///
+    /// **TwoTupleError::Overflow**: If the result is out of domain range.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let b = TwoTuple::new_by_label_index(&domain, 1, 0.25).unwrap();
+    ///
+    /// assert_eq!(b.checked_add(&b), Err(TwoTupleError::Overflow { domain: &domain, beta: 2.5 }));
+    /// ```
+    pub fn checked_add<'a>(&'a self, other: &'a Self) -> Result<Self, TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        self.checked_delta(self.inverse_delta() + other.inverse_delta())
+    }
+
+    /// Difference of valuations, saturated to the domain range.
+    ///
+    /// Computed as **Δ(max(Δ⁻¹(a) - Δ⁻¹(b), 0))**. It is also available as the `-` operator.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let b = TwoTuple::new_by_label_name(&domain, "b", 0.2).unwrap();
+    /// let d = TwoTuple::new_by_label_name(&domain, "d", -0.4).unwrap();
+    ///
+    /// assert_eq!(d.saturating_sub(&b), TwoTuple::new_by_label_name(&domain, "b", 0.4).unwrap());
+    /// assert_eq!(b.saturating_sub(&d), TwoTuple::new_by_label_name(&domain, "a", 0.0).unwrap());
+    /// assert_eq!(&d - &b, d.saturating_sub(&b));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If valuations are defined over different domains. See [TwoTuple::checked_sub].
+    pub fn saturating_sub(&self, other: &Self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.saturated(self.checked_sub(other))
+    }
+
+    /// Difference of valuations.
+    ///
+    /// Computed as **Δ(Δ⁻¹(a) - Δ⁻¹(b))**.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let b = TwoTuple::new_by_label_name(&domain, "b", 0.2).unwrap();
+    /// let d = TwoTuple::new_by_label_name(&domain, "d", -0.4).unwrap();
+    ///
+    /// assert_eq!(d.checked_sub(&b), TwoTuple::new_by_label_name(&domain, "b", 0.4));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// **TwoTupleError::Overflow**: If the result is out of domain range.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let a = TwoTuple::new_by_label_index(&domain, 0, 0.25).unwrap();
+    /// let b = TwoTuple::new_by_label_index(&domain, 1, 0.25).unwrap();
+    ///
+    /// assert_eq!(a.checked_sub(&b), Err(TwoTupleError::Overflow { domain: &domain, beta: -1.0 }));
+    /// ```
+    pub fn checked_sub<'a>(&'a self, other: &'a Self) -> Result<Self, TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        self.checked_delta(self.inverse_delta() - other.inverse_delta())
+    }
+
+    /// Product by a scalar, saturated to the domain range.
+    ///
+    /// Computed as **Δ(Δ⁻¹(a)·k)**, saturated to `[0, g]`. It is also available as the `*`
+    /// operator.
+    ///
+    /// # Arguments
+    /// * `factor`: Scalar factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let b = TwoTuple::new_by_label_name(&domain, "b", 0.2).unwrap();
+    ///
+    /// assert_eq!(b.saturating_mul(1.5), TwoTuple::new_by_label_name(&domain, "c", -0.2).unwrap());
+    /// assert_eq!(b.saturating_mul(5.0), TwoTuple::new_by_label_name(&domain, "e", 0.0).unwrap());
+    /// assert_eq!(b.saturating_mul(-1.0), TwoTuple::new_by_label_name(&domain, "a", 0.0).unwrap());
+    /// assert_eq!(&b * 1.5, b.saturating_mul(1.5));
+    /// ```
+    pub fn saturating_mul(&self, factor: f32) -> Self {
+        self.saturated(self.checked_mul(factor))
+    }
+
+    /// Product by a scalar.
+    ///
+    /// Computed as **Δ(Δ⁻¹(a)·k)**.
+    ///
+    /// # Arguments
+    /// * `factor`: Scalar factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let b = TwoTuple::new_by_label_name(&domain, "b", 0.2).unwrap();
+    ///
+    /// assert_eq!(b.checked_mul(1.5), TwoTuple::new_by_label_name(&domain, "c", -0.2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::Overflow**: If the result is out of domain range.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let b = TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap();
+    ///
+    /// assert_eq!(b.checked_mul(3.0), Err(TwoTupleError::Overflow { domain: &domain, beta: 3.0 }));
+    /// ```
+    pub fn checked_mul(&self, factor: f32) -> Result<Self, TwoTupleError<'_, T>> {
+        self.checked_delta(self.inverse_delta() * factor)
+    }
+
+    /// Quotient by a scalar, saturated to the domain range.
+    ///
+    /// Computed as **Δ(Δ⁻¹(a)/k)**, saturated to `[0, g]`. It is also available as the `/`
+    /// operator.
+    ///
+    /// # Arguments
+    /// * `divisor`: Scalar divisor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let d = TwoTuple::new_by_label_name(&domain, "d", 0.0).unwrap();
+    ///
+    /// assert_eq!(d.saturating_div(2.0), TwoTuple::new_by_label_name(&domain, "c", -0.5).unwrap());
+    /// assert_eq!(d.saturating_div(0.5), TwoTuple::new_by_label_name(&domain, "e", 0.0).unwrap());
+    /// assert_eq!(&d / 2.0, d.saturating_div(2.0));
+    /// ```
+    pub fn saturating_div(&self, divisor: f32) -> Self {
+        self.saturated(self.checked_div(divisor))
+    }
+
+    /// Quotient by a scalar.
+    ///
+    /// Computed as **Δ(Δ⁻¹(a)/k)**.
+    ///
+    /// # Arguments
+    /// * `divisor`: Scalar divisor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let d = TwoTuple::new_by_label_name(&domain, "d", 0.0).unwrap();
+    ///
+    /// assert_eq!(d.checked_div(2.0), TwoTuple::new_by_label_name(&domain, "c", -0.5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::Overflow**: If the result is out of domain range (e.g., `divisor == 0`).
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let b = TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     b.checked_div(0.0),
+    ///     Err(TwoTupleError::Overflow { domain: &domain, beta: f32::INFINITY })
+    /// );
+    /// ```
+    pub fn checked_div(&self, divisor: f32) -> Result<Self, TwoTupleError<'_, T>> {
+        self.checked_delta(self.inverse_delta() / divisor)
+    }
+
+    /// Compares `self` with `other`, defined over the same domain.
+    ///
+    /// Valuations are compared lexicographically by `(index, alpha)`, which is equivalent to
+    /// comparing their symbolic values ([TwoTuple::inverse_delta]).
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::cmp::Ordering;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let valuation = |index, alpha| TwoTuple::new_by_label_index(&domain, index, alpha).unwrap();
+    ///
+    /// for ((i1, a1), (i2, a2), ordering) in [
+    ///     ((1, 0.2), (2, -0.4), Ordering::Less),
+    ///     ((2, -0.1), (1, 0.4), Ordering::Greater),
+    ///     ((2, 0.1), (2, -0.1), Ordering::Greater),
+    ///     ((3, 0.0), (3, 0.0), Ordering::Equal),
+    ///     ((3, -0.0), (3, 0.0), Ordering::Equal),
+    /// ] {
+    ///     assert_eq!(valuation(i1, a1).compare(&valuation(i2, a2)), Ok(ordering));
+    ///     assert_eq!(valuation(i1, a1).partial_cmp(&valuation(i2, a2)), Some(ordering));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.0)
+    ///         .unwrap()
+    ///         .compare(&TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap()),
+    ///     Err(TwoTupleError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn compare<'a>(&'a self, other: &'a Self) -> Result<Ordering, TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        self.check_same_domain(other)?;
+        Ok(self.symbolic_cmp(other))
+    }
+
+    /// Lexicographic `(index, alpha)` ordering, consistent with `PartialEq` (`-0.0 == 0.0`).
+    fn symbolic_cmp(&self, other: &Self) -> Ordering {
+        // Adding 0.0 turns -0.0 into 0.0, which total_cmp would order before it
+        self.index
+            .cmp(&other.index)
+            .then((self.alpha + 0.0).total_cmp(&(other.alpha + 0.0)))
+    }
+
+    /// Returns the minimum of `self` and `other`, or `self` if they are equal.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let low = TwoTuple::new_by_label_index(&domain, 1, 0.3).unwrap();
+    /// let high = TwoTuple::new_by_label_index(&domain, 2, -0.3).unwrap();
+    ///
+    /// assert_eq!(low.min(&high), Ok(&low));
+    /// assert_eq!(high.min(&low), Ok(&low));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn min<'a>(&'a self, other: &'a Self) -> Result<&'a Self, TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        Ok(match self.compare(other)? {
+            Ordering::Greater => other,
+            _ => self,
+        })
+    }
+
+    /// Returns the maximum of `self` and `other`, or `other` if they are equal.
+    ///
+    /// # Arguments
+    /// * `other`: Valuation over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let low = TwoTuple::new_by_label_index(&domain, 1, 0.3).unwrap();
+    /// let high = TwoTuple::new_by_label_index(&domain, 2, -0.3).unwrap();
+    ///
+    /// assert_eq!(low.max(&high), Ok(&high));
+    /// assert_eq!(high.max(&low), Ok(&high));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains.
+    pub fn max<'a>(&'a self, other: &'a Self) -> Result<&'a Self, TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        Ok(match self.compare(other)? {
+            Ordering::Greater => self,
+            _ => other,
+        })
+    }
+
+    /// Restricts the valuation to the range `[min, max]`.
+    ///
+    /// # Arguments
+    /// * `min`: Lower bound, over the same domain.
+    /// * `max`: Upper bound, over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let low = TwoTuple::new_by_label_index(&domain, 1, 0.3).unwrap();
+    /// let high = TwoTuple::new_by_label_index(&domain, 2, -0.3).unwrap();
+    /// let higher = TwoTuple::new_by_label_index(&domain, 3, 0.0).unwrap();
+    ///
+    /// assert_eq!(low.clamp(&high, &higher), Ok(&high));
+    /// assert_eq!(high.clamp(&low, &higher), Ok(&high));
+    /// assert_eq!(higher.clamp(&low, &high), Ok(&high));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains.
+    ///
+    /// # Panics
+    ///
+    /// If `min > max`.
+    pub fn clamp<'a>(
+        &'a self,
+        min: &'a Self,
+        max: &'a Self,
+    ) -> Result<&'a Self, TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        assert!(min.compare(max)? != Ordering::Greater, "min > max in clamp");
+        Ok(if self.compare(min)? == Ordering::Less {
+            min
+        } else if self.compare(max)? == Ordering::Greater {
+            max
+        } else {
+            self
+        })
+    }
+
+    /// Sorts `valuations` in ascending order (see [TwoTuple::compare]).
+    ///
+    /// # Arguments
+    /// * `valuations`: Valuations over the same domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::TwoTuple;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let mut valuations = vec![
+    ///     TwoTuple::new_by_label_index(&domain, 3, 0.1).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap(),
+    ///     TwoTuple::new_by_label_index(&domain, 3, -0.2).unwrap(),
+    /// ];
+    ///
+    /// TwoTuple::sort(&mut valuations).unwrap();
+    /// assert_eq!(
+    ///     valuations.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+    ///     vec!["(b, 0.20)", "(d, -0.20)", "(d, 0.10)"]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **TwoTupleError::DomainMismatch**: If valuations are defined over different domains, in which
+    /// case `valuations` are left untouched.
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, TwoTupleError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let other = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let mut valuations = vec![
+    ///     TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap(),
+    ///     TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     TwoTuple::sort(&mut valuations),
+    ///     Err(TwoTupleError::DomainMismatch { expected: &domain, found: &other })
+    /// );
+    /// ```
+    pub fn sort<'a>(valuations: &'a mut [Self]) -> Result<(), TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        if let Some(position) = valuations
+            .iter()
+            .position(|valuation| *valuation.domain != *valuations[0].domain)
+        {
+            let valuations: &'a [Self] = valuations;
+            return Err(TwoTupleError::DomainMismatch {
+                expected: &valuations[0].domain,
+                found: &valuations[position].domain,
+            });
+        }
+        valuations.sort_by(TwoTuple::symbolic_cmp);
+        Ok(())
+    }
+
+    /// Checks that `other` is defined over the same domain.
+    pub(crate) fn check_same_domain<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> Result<(), TwoTupleError<'a, T>>
+    where
+        T: PartialEq,
+    {
+        if *self.domain == *other.domain {
+            Ok(())
+        } else {
+            Err(TwoTupleError::DomainMismatch {
+                expected: &self.domain,
+                found: &other.domain,
+            })
+        }
+    }
+
+    /// Valuation over the same domain given its symbolic value, if it is in domain range.
+    fn checked_delta(&self, beta: f32) -> Result<Self, TwoTupleError<'_, T>> {
+        if (0.0..=(self.domain.cardinality() - 1) as f32).contains(&beta) {
+            Ok(self.delta_in_domain(beta))
+        } else {
+            Err(TwoTupleError::Overflow {
+                domain: &self.domain,
+                beta,
+            })
+        }
+    }
+
+    /// Result of a checked operation, saturated to the domain range on overflow.
+    ///
+    /// Panics on any other error.
+    fn saturated(&self, result: Result<Self, TwoTupleError<'_, T>>) -> Self {
+        match result {
+            Ok(valuation) => valuation,
+            Err(TwoTupleError::Overflow { domain: _, beta }) => {
+                self.delta_in_domain(beta.max(0.0).min((self.domain.cardinality() - 1) as f32))
+            }
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Valuation over the same domain given its symbolic value (Δ).
+    pub(crate) fn delta_in_domain(&self, beta: f32) -> Self {
+        Self::delta_in(self.domain.clone(), beta)
+    }
+
+    /// Valuation over `domain` given its symbolic value (Δ), which must be in domain range.
+    pub(crate) fn delta_in(domain: H, beta: f32) -> Self {
+        let beta = utilities::math::round_f32(beta, 5);
+        let index = beta.round() as usize;
+        let alpha = utilities::math::round_f32(beta - index as f32, 5);
+        Self {
+            domain,
+            index,
+            alpha,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Borrowed view of the valuation.
+    ///
+    /// Gives access to the API only available for borrowed domains (unification, conversions...).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Single, TwoTuple};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let valuation = TwoTuple::new_shared_by_label_index(&domain, 1, 0.2).unwrap();
+    /// assert_eq!(valuation.as_borrowed(), TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap());
+    /// assert_eq!(Single::try_from(&valuation.as_borrowed()).unwrap().to_string(), "b");
+    /// ```
+    pub fn as_borrowed(&self) -> TwoTuple<'_, T> {
+        TwoTuple {
+            domain: &self.domain,
+            index: self.index,
+            alpha: self.alpha,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_two_tuple_op {
+    ($ops_trait:ident, $ops_fn:ident, $method:ident) => {
+        impl<'domain, T, H> ops::$ops_trait<&TwoTuple<'domain, T, H>> for &TwoTuple<'domain, T, H>
+        where
+            T: LabelMembership + PartialEq,
+            H: DomainHandle<Qualitative<T>>,
+        {
+            type Output = TwoTuple<'domain, T, H>;
+
+            fn $ops_fn(self, other: &TwoTuple<'domain, T, H>) -> Self::Output {
+                self.$method(other)
+            }
+        }
+
+        impl<'domain, T, H> ops::$ops_trait<&TwoTuple<'domain, T, H>> for TwoTuple<'domain, T, H>
+        where
+            T: LabelMembership + PartialEq,
+            H: DomainHandle<Qualitative<T>>,
+        {
+            type Output = TwoTuple<'domain, T, H>;
+
+            fn $ops_fn(self, other: &TwoTuple<'domain, T, H>) -> Self::Output {
+                self.$method(other)
+            }
+        }
+
+        impl<'domain, T, H> ops::$ops_trait<TwoTuple<'domain, T, H>> for &TwoTuple<'domain, T, H>
+        where
+            T: LabelMembership + PartialEq,
+            H: DomainHandle<Qualitative<T>>,
+        {
+            type Output = TwoTuple<'domain, T, H>;
+
+            fn $ops_fn(self, other: TwoTuple<'domain, T, H>) -> Self::Output {
+                self.$method(&other)
+            }
+        }
+
+        impl<'domain, T, H> ops::$ops_trait<TwoTuple<'domain, T, H>> for TwoTuple<'domain, T, H>
+        where
+            T: LabelMembership + PartialEq,
+            H: DomainHandle<Qualitative<T>>,
+        {
+            type Output = TwoTuple<'domain, T, H>;
+
+            fn $ops_fn(self, other: TwoTuple<'domain, T, H>) -> Self::Output {
+                self.$method(&other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_two_tuple_scalar_op {
+    ($ops_trait:ident, $ops_fn:ident, $method:ident) => {
+        impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> ops::$ops_trait<f32>
+            for &TwoTuple<'domain, T, H>
+        {
+            type Output = TwoTuple<'domain, T, H>;
+
+            fn $ops_fn(self, scalar: f32) -> Self::Output {
+                self.$method(scalar)
+            }
+        }
+
+        impl<'domain, T: LabelMembership, H: DomainHandle<Qualitative<T>>> ops::$ops_trait<f32>
+            for TwoTuple<'domain, T, H>
+        {
+            type Output = TwoTuple<'domain, T, H>;
+
+            fn $ops_fn(self, scalar: f32) -> Self::Output {
+                self.$method(scalar)
+            }
+        }
+    };
+}
+
+// Note: impl_ops can't declare the generic parameters of TwoTuple, so operators are generated here
+// the same way (borrowed and owned operands) and delegate on the saturating methods.
+impl_two_tuple_op!(Add, add, saturating_add);
+impl_two_tuple_op!(Sub, sub, saturating_sub);
+impl_two_tuple_scalar_op!(Mul, mul, saturating_mul);
+impl_two_tuple_scalar_op!(Div, div, saturating_div);
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::Qualitative;
+    use crate::fuzzy::membership::Trapezoidal;
+    use crate::qualitative_domain;
+    use crate::valuation::TwoTuple;
+
+    fn domain() -> Qualitative<Trapezoidal> {
+        qualitative_domain![
+            "a" => vec![0.0, 0.0, 0.25],
+            "b" => vec![0.0, 0.25, 0.5],
+            "c" => vec![0.25, 0.5, 0.75],
+            "d" => vec![0.5, 0.75, 1.0],
+            "e" => vec![0.75, 1.0, 1.0]
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn add_references() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 2, 0.4).unwrap();
+
+        let sum = &a + &b;
+        assert_eq!(sum.index(), 4);
+        assert_eq!(sum.alpha(), -0.4);
+    }
+
+    #[test]
+    fn add_ownerships() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 2, 0.4).unwrap();
+
+        let sum = a + b;
+        assert_eq!(sum.index(), 4);
+        assert_eq!(sum.alpha(), -0.4);
+    }
+
+    #[test]
+    fn add_ownership_reference() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 2, 0.4).unwrap();
+
+        let sum = a + &b;
+        assert_eq!(sum.index(), 4);
+        assert_eq!(sum.alpha(), -0.4);
+    }
+
+    #[test]
+    fn add_reference_ownership() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 2, 0.4).unwrap();
+
+        let sum = &a + b;
+        assert_eq!(sum.index(), 4);
+        assert_eq!(sum.alpha(), -0.4);
+    }
+
+    #[test]
+    fn add_saturates() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 3, 0.0).unwrap();
+
+        let sum = &a + &a;
+        assert_eq!(sum.index(), 4);
+        assert_eq!(sum.alpha(), 0.0);
+    }
+
+    #[test]
+    fn sub_references() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+
+        let difference = &a - &b;
+        assert_eq!(difference.index(), 1);
+        assert_eq!(difference.alpha(), 0.4);
+    }
+
+    #[test]
+    fn sub_ownerships() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 3, -0.4).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+
+        let difference = a - b;
+        assert_eq!(difference.index(), 1);
+        assert_eq!(difference.alpha(), 0.4);
+    }
+
+    #[test]
+    fn sub_saturates() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap();
+        let b = TwoTuple::new_by_label_index(&domain, 2, 0.0).unwrap();
+
+        let difference = &a - &b;
+        assert_eq!(difference.index(), 0);
+        assert_eq!(difference.alpha(), 0.0);
+    }
+
+    #[test]
+    fn mul_reference() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+
+        let product = &a * 1.5;
+        assert_eq!(product.index(), 2);
+        assert_eq!(product.alpha(), -0.2);
+    }
+
+    #[test]
+    fn mul_ownership() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.2).unwrap();
+
+        let product = a * 1.5;
+        assert_eq!(product.index(), 2);
+        assert_eq!(product.alpha(), -0.2);
+    }
+
+    #[test]
+    fn div_reference() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 3, 0.0).unwrap();
+
+        let quotient = &a / 2.0;
+        assert_eq!(quotient.index(), 2);
+        assert_eq!(quotient.alpha(), -0.5);
+    }
+
+    #[test]
+    fn div_ownership() {
+        let domain = domain();
+        let a = TwoTuple::new_by_label_index(&domain, 3, 0.0).unwrap();
+
+        let quotient = a / 0.0;
+        assert_eq!(quotient.index(), 4);
+        assert_eq!(quotient.alpha(), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_different_domains() {
+        let domain = domain();
+        let other = qualitative_domain![
+            "a" => vec![0.0, 0.0, 1.0],
+            "b" => vec![0.0, 1.0, 1.0]
+        ]
+        .unwrap();
+        let a = TwoTuple::new_by_label_index(&domain, 1, 0.0).unwrap();
+        let b = TwoTuple::new_by_label_index(&other, 1, 0.0).unwrap();
+
+        let _ = &a + &b;
+    }
+}
@@ -1,11 +1,15 @@
 //! Valuations used for assessments.
 
-pub use interval::{Interval, IntervalError};
+use crate::domain::Qualitative;
+use crate::fuzzy::membership::Trapezoidal;
+use std::fmt::{Display, Formatter};
+
+pub use interval::{Interval, IntervalError, SharedInterval};
 pub use linguistic::{
-    Hesitant, HesitantError, HesitantRelation, Linguistic, Single, SingleError, TwoTuple,
-    TwoTupleError,
+    Hesitant, HesitantError, HesitantRelation, HesitantTransformPolicy, Linguistic, SharedHesitant,
+    SharedSingle, SharedTwoTuple, Single, SingleError, TwoTuple, TwoTupleError,
 };
-pub use numeric::{Numeric, NumericError};
+pub use numeric::{Numeric, NumericError, SharedNumeric};
 pub use unification::*;
 
 /// Interval struct and related implementations.
@@ -20,5 +24,101 @@ pub mod numeric;
 /// Implementations for unification.
 pub mod unification;
 
+/// Valuation kinds.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ValuationKind {
+    /// Numeric valuation.
+    Numeric,
+    /// Interval valuation.
+    Interval,
+    /// Single linguistic valuation.
+    Single,
+    /// TwoTuple linguistic valuation.
+    TwoTuple,
+    /// Hesitant linguistic valuation.
+    Hesitant,
+    /// Unified linguistic valuation.
+    Unified,
+}
+
+impl Display for ValuationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ValuationKind::*;
+        match &self {
+            Numeric => write!(f, "numeric"),
+            Interval => write!(f, "interval"),
+            Single => write!(f, "single"),
+            TwoTuple => write!(f, "2-tuple"),
+            Hesitant => write!(f, "hesitant"),
+            Unified => write!(f, "unified"),
+        }
+    }
+}
+
 /// Base trait for valuations.
-pub trait Valuation {}
+///
+/// It allows handling heterogeneous valuations uniformly (e.g. `Vec<Box<dyn Valuation>>`).
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::domain::Quantitative;
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Interval, Numeric, Single, TwoTuple, ValuationKind};
+/// # use assessment::Valuation;
+/// let numeric_domain = Quantitative::new(0.0, 10.0).unwrap();
+/// let linguistic_domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+///
+/// let valuations: Vec<Box<dyn Valuation>> = vec![
+///     Box::new(Numeric::new(&numeric_domain, 7.5).unwrap()),
+///     Box::new(Interval::new(&numeric_domain, 2.5, 5.0).unwrap()),
+///     Box::new(Single::new_by_label_name(&linguistic_domain, "b").unwrap()),
+///     Box::new(TwoTuple::new_by_label_name(&linguistic_domain, "c", -0.25).unwrap()),
+/// ];
+///
+/// assert_eq!(
+///     valuations.iter().map(|v| v.kind()).collect::<Vec<ValuationKind>>(),
+///     vec![ValuationKind::Numeric, ValuationKind::Interval, ValuationKind::Single, ValuationKind::TwoTuple]
+/// );
+/// assert_eq!(
+///     valuations.iter().map(|v| format!("{}", v)).collect::<Vec<String>>(),
+///     vec!["7.5", "[2.5, 5]", "b", "(c, -0.25)"]
+/// );
+/// assert_eq!(
+///     valuations.iter().map(|v| v.normalization().value()).collect::<Vec<(f64, f64)>>(),
+///     vec![(0.75, 0.75), (0.25, 0.5), (0.5, 0.5), (0.875, 0.875)]
+/// );
+/// assert_eq!(
+///     valuations.iter().map(|v| v.negation().to_string()).collect::<Vec<String>>(),
+///     vec!["2.5", "[5, 7.5]", "b", "(a, 0.25)"]
+/// );
+/// for valuation in &valuations {
+///     assert_eq!(*valuation.unification(&blts).unwrap().domain(), blts);
+/// }
+/// ```
+pub trait Valuation: Display {
+    /// Returns valuation kind.
+    fn kind(&self) -> ValuationKind;
+
+    /// Valuation normalized in domain 0.0 to 1.0.
+    ///
+    /// Note that point valuations are represented by intervals with `min == max`.
+    fn normalization(&self) -> Interval<'static, f64>;
+
+    /// Valuation negation.
+    fn negation(&self) -> Box<dyn Valuation + '_>;
+
+    /// Unification of the valuation in a given domain.
+    ///
+    /// # Arguments
+    /// * `domain`: Domain in which perform the unification.
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>>;
+}
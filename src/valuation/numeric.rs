@@ -1,21 +1,39 @@
 use crate::domain::quantitative::NORMALIZATION_DOMAIN;
-use crate::domain::{Quantitative, QuantitativeLimit};
+use crate::domain::{DomainHandle, Qualitative, Quantitative, QuantitativeLimit};
+use crate::fuzzy::membership::Trapezoidal;
+use crate::utilities::parser::{ParseError, Parser};
+use crate::valuation::{Interval, Unified, UnifiedError, ValuationKind};
 use crate::Valuation;
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, Sub};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// Numeric valuation.
+///
+/// The domain is borrowed by default. See [SharedNumeric] for valuations owning their domain.
 #[derive(Debug, PartialEq)]
-pub struct Numeric<'domain, T: QuantitativeLimit> {
-    domain: &'domain Quantitative<T>,
+pub struct Numeric<
+    'domain,
+    T: QuantitativeLimit,
+    H: DomainHandle<Quantitative<T>> = &'domain Quantitative<T>,
+> {
+    domain: H,
     value: T,
+    lifetime: PhantomData<&'domain ()>,
 }
 
+/// Numeric valuation sharing the ownership of its domain.
+pub type SharedNumeric<T> = Numeric<'static, T, Arc<Quantitative<T>>>;
+
 /// Numeric errors types.
 #[derive(Debug, PartialEq)]
 pub enum NumericError<T: QuantitativeLimit> {
     /// Value outside domain range.
     OutsideRange { value: T, inf: T, sup: T },
+    /// Malformed valuation text.
+    InvalidSyntax { error: ParseError },
 }
 
 // Note: + Display added because clion doesn't detect here correctly the trait_alias feature
@@ -30,11 +48,69 @@ impl<T: QuantitativeLimit + Display> Display for NumericError<T> {
                     inf, sup, value
                 )
             }
+            InvalidSyntax { error } => {
+                write!(f, "Invalid syntax: {}", error)
+            }
         }
     }
 }
 
-impl<'domain, T: QuantitativeLimit> Valuation for Numeric<'domain, T> {}
+impl<'domain, T: QuantitativeLimit, H: DomainHandle<Quantitative<T>>> Display
+    for Numeric<'domain, T, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Numeric valuations as generic valuations.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::domain::Quantitative;
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Numeric, ValuationKind};
+/// # use assessment::Valuation;
+/// let domain = Quantitative::new(0, 8).unwrap();
+/// let valuation: Box<dyn Valuation> = Box::new(Numeric::new(&domain, 2).unwrap());
+///
+/// assert_eq!(valuation.kind(), ValuationKind::Numeric);
+/// assert_eq!(format!("{}", valuation), "2");
+/// assert_eq!(valuation.normalization().value(), (0.25, 0.25));
+/// assert_eq!(format!("{}", valuation.negation()), "6");
+///
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// assert_eq!(*valuation.unification(&blts).unwrap().measures(), vec![0.0, 1.0, 0.0, 0.0, 0.0]);
+/// ```
+impl<'domain, T: QuantitativeLimit + Into<f64>, H: DomainHandle<Quantitative<T>>> Valuation
+    for Numeric<'domain, T, H>
+where
+    T: Mul<Output = T>,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Div<Output = T>,
+{
+    fn kind(&self) -> ValuationKind {
+        ValuationKind::Numeric
+    }
+
+    fn normalization(&self) -> Interval<'static, f64> {
+        let value = self.normalize().value();
+        Interval::new(&NORMALIZATION_DOMAIN, value, value).unwrap()
+    }
+
+    fn negation(&self) -> Box<dyn Valuation + '_> {
+        Box::new(self.neg())
+    }
+
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        self.as_borrowed().unification(domain)
+    }
+}
 
 // Note: + <Trait> added because clion doesn't detect here correctly the trait_alias feature
 impl<
@@ -98,31 +174,67 @@ impl<
                 sup: domain.sup(),
             })
         } else {
-            Ok(Self { domain, value })
+            Ok(Self {
+                domain,
+                value,
+                lifetime: PhantomData,
+            })
         }
     }
 
-    /// Returns valuation values.
+    /// Parses a valuation in `domain` from `text`, such as `0.7`.
+    ///
+    /// # Arguments
+    /// * `domain`: A quantitative domain reference.
+    /// * `text`: Valuation text.
     ///
     /// # Examples
     ///
     /// ```
     /// # use assessment::valuation::Numeric;
     /// # use assessment::domain::Quantitative;
-    /// let domain = Quantitative::new(1, 5).unwrap();
-    /// let valuation = Numeric::new(&domain, 2).unwrap();
-    /// assert_eq!(valuation.value(), 2);
+    /// let domain = Quantitative::new(0.0, 1.0).unwrap();
+    /// assert_eq!(Numeric::parse_in_domain(&domain, " 0.7 "), Ok(Numeric::new(&domain, 0.7).unwrap()));
     /// ```
     ///
+    /// # Errors
+    ///
+    /// **NumericError::InvalidSyntax**: If `text` isn't a number.
+    ///
     /// ```
-    /// # use assessment::valuation::Numeric;
+    /// # use assessment::valuation::{Numeric, NumericError};
     /// # use assessment::domain::Quantitative;
-    /// let domain = Quantitative::new(1.0, 5.7).unwrap();
-    /// let valuation = Numeric::new(&domain, 2.0).unwrap();
-    /// assert_eq!(valuation.value(), 2.0);
+    /// # use assessment::utilities::parser::ParseError;
+    /// let domain = Quantitative::new(0, 10).unwrap();
+    /// assert_eq!(
+    ///     Numeric::parse_in_domain(&domain, "seven"),
+    ///     Err(NumericError::InvalidSyntax {
+    ///         error: ParseError::InvalidNumber { position: 0, value: String::from("seven") }
+    ///     })
+    /// );
     /// ```
-    pub fn value(&self) -> T {
-        self.value
+    ///
+    /// Other errors: see [Numeric::new].
+    ///
+    /// ```
+    /// # use assessment::valuation::{Numeric, NumericError};
+    /// # use assessment::domain::Quantitative;
+    /// let domain = Quantitative::new(0, 10).unwrap();
+    /// assert_eq!(
+    ///     Numeric::parse_in_domain(&domain, "12"),
+    ///     Err(NumericError::OutsideRange { value: 12, inf: 0, sup: 10 })
+    /// );
+    /// ```
+    pub fn parse_in_domain(
+        domain: &'domain Quantitative<T>,
+        text: &str,
+    ) -> Result<Self, NumericError<T>>
+    where
+        T: FromStr,
+    {
+        let value = Parser::parse_all(text, |parser| parser.number::<T>())
+            .map_err(|error| NumericError::InvalidSyntax { error })?;
+        Numeric::new(domain, value)
     }
 
     /// Returns valuation domain.
@@ -140,7 +252,92 @@ impl<
     pub fn domain(&self) -> &'domain Quantitative<T> {
         self.domain
     }
+}
+
+// Note: + <Trait> added because clion doesn't detect here correctly the trait_alias feature
+impl<
+        T: QuantitativeLimit + Copy + Debug + Display + Into<f64> + Add<Output = T> + Sub<Output = T>,
+    > SharedNumeric<T>
+{
+    /// Creates a new valuation sharing the ownership of `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted quantitative domain.
+    /// * `value`: Valuation value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Numeric, SharedNumeric};
+    /// # use assessment::domain::Quantitative;
+    /// # use std::sync::Arc;
+    /// let valuation: SharedNumeric<i32> = {
+    ///     let domain = Arc::new(Quantitative::new(1, 5).unwrap());
+    ///     Numeric::new_shared(&domain, 2).unwrap()
+    /// };
+    ///
+    /// let handle = std::thread::spawn(move || valuation.neg().value());
+    /// assert_eq!(handle.join().unwrap(), 4);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [Numeric::new].
+    pub fn new_shared(domain: &Arc<Quantitative<T>>, value: T) -> Result<Self, NumericError<T>> {
+        let valuation = Numeric::new(domain, value)?;
+        Ok(Self {
+            domain: Arc::clone(domain),
+            value: valuation.value,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::domain::Quantitative;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(Quantitative::new(1.0, 5.7).unwrap());
+    /// let valuation = Numeric::new_shared(&domain, 2.0).unwrap();
+    /// assert_eq!(valuation.domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Quantitative<T> {
+        &self.domain
+    }
+}
 
+// Note: + <Trait> added because clion doesn't detect here correctly the trait_alias feature
+impl<
+        'domain,
+        T: QuantitativeLimit + Copy + Debug + Display + Into<f64> + Add<Output = T> + Sub<Output = T>,
+        H: DomainHandle<Quantitative<T>>,
+    > Numeric<'domain, T, H>
+{
+    /// Returns valuation values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::domain::Quantitative;
+    /// let domain = Quantitative::new(1, 5).unwrap();
+    /// let valuation = Numeric::new(&domain, 2).unwrap();
+    /// assert_eq!(valuation.value(), 2);
+    /// ```
+    ///
+    /// ```
+    /// # use assessment::valuation::Numeric;
+    /// # use assessment::domain::Quantitative;
+    /// let domain = Quantitative::new(1.0, 5.7).unwrap();
+    /// let valuation = Numeric::new(&domain, 2.0).unwrap();
+    /// assert_eq!(valuation.value(), 2.0);
+    /// ```
+    pub fn value(&self) -> T {
+        self.value
+    }
     /// Value normalized in domain 0.0 to 1.0.
     ///
     /// Note that the type of value is f64.
@@ -163,11 +360,12 @@ impl<
     /// assert_eq!(normalized.value(), 0.5);
     /// assert_eq!(*normalized.domain(), NORMALIZATION_DOMAIN);
     /// ```
-    pub fn normalize(&self) -> Numeric<f64> {
+    pub fn normalize(&self) -> Numeric<'static, f64> {
         Numeric::<f64> {
             value: (self.value.into() - self.domain.inf().into())
                 / (self.domain.sup().into() - self.domain.inf().into()),
             domain: &NORMALIZATION_DOMAIN,
+            lifetime: PhantomData,
         }
     }
 
@@ -185,8 +383,45 @@ impl<
     /// ```
     pub fn neg(&self) -> Self {
         Self {
-            domain: self.domain,
+            domain: self.domain.clone(),
             value: self.domain.sup() + self.domain.inf() - self.value(),
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Borrowed view of the valuation.
+    ///
+    /// Gives access to the API only available for borrowed domains (unification, conversions...).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{Interval, Numeric};
+    /// # use assessment::domain::Quantitative;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(Quantitative::new(0, 10).unwrap());
+    /// let valuation = Numeric::new_shared(&domain, 2).unwrap();
+    /// assert_eq!(valuation.as_borrowed(), Numeric::new(&domain, 2).unwrap());
+    /// assert_eq!(Interval::try_from(&valuation.as_borrowed()).unwrap().value(), (2, 2));
+    /// ```
+    pub fn as_borrowed(&self) -> Numeric<'_, T> {
+        Numeric {
+            domain: &self.domain,
+            value: self.value,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'domain, H: DomainHandle<Quantitative<f64>>> Numeric<'domain, f64, H> {
+    /// Valuation over the same domain given a value normalized in domain 0.0 to 1.0 (see
+    /// [Numeric::normalize]), saturated to the domain range.
+    pub(crate) fn denormalize(&self, value: f64) -> Self {
+        let (inf, sup) = (self.domain.inf(), self.domain.sup());
+        Self {
+            domain: self.domain.clone(),
+            value: (inf + value * (sup - inf)).clamp(inf, sup),
+            lifetime: PhantomData,
         }
     }
 }
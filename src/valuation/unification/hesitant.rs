@@ -0,0 +1,283 @@
+use crate::domain::Qualitative;
+use crate::fuzzy::membership::Trapezoidal;
+use crate::fuzzy::LabelMembership;
+use crate::valuation::{Hesitant, HesitantTransformPolicy, Unified, UnifiedError};
+
+impl<'domain, T: LabelMembership> Hesitant<'domain, T> {
+    /// Unification of a Hesitant valuation in a new domain, by label coverage.
+    ///
+    /// Covered labels are placed at their symbolic positions in `domain`. Labels between the
+    /// first and the last position are fully covered, and the closest labels out of them are
+    /// partially covered depending on their distance.
+    ///
+    /// To unify by maxmin against the fuzzy envelope of the valuation, see
+    /// [Hesitant::max_min_in_domain].
+    ///
+    /// # Arguments
+    /// * `domain`: Domain in which perform the unification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, Unified};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// for (text, measures) in [
+    ///     ("b", vec![0.0, 0.0, 1.0, 0.0, 0.0]),
+    ///     ("at least b", vec![0.0, 0.0, 1.0, 1.0, 1.0]),
+    ///     ("lower than c", vec![1.0, 1.0, 1.0, 0.0, 0.0]),
+    /// ] {
+    ///     let valuation = Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///     assert_eq!(*valuation.unification_in_domain(&unification_domain).unwrap().measures(), measures);
+    /// }
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&unification_domain, "between b and c").unwrap();
+    /// assert_eq!(*valuation.unification_in_domain(&domain).unwrap().measures(), vec![0.5, 1.0, 0.0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, UnifiedError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&domain, "at most b").unwrap();
+    /// assert_eq!(
+    ///     valuation.unification_in_domain(&unification_domain),
+    ///     Err(UnifiedError::NonBLTSDomain { domain: &unification_domain })
+    /// );
+    /// ```
+    ///
+    pub fn unification_in_domain<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        let ratio = (domain.cardinality() - 1) as f32 / (self.domain().cardinality() - 1) as f32;
+        let (lower, upper) = self.indexes();
+        let (lower, upper) = (lower as f32 * ratio, upper as f32 * ratio);
+        let measures = (0..domain.cardinality())
+            .map(|i| {
+                let position = i as f32;
+                if position < lower {
+                    (1. - (lower - position)).max(0.)
+                } else if position > upper {
+                    (1. - (position - upper)).max(0.)
+                } else {
+                    1.
+                }
+            })
+            .collect::<Vec<f32>>();
+        Unified::new(domain, measures)
+    }
+}
+
+impl<'domain> Hesitant<'domain, Trapezoidal> {
+    /// Unification of a Hesitant valuation in a new domain, by maxmin against its fuzzy envelope.
+    ///
+    /// Each measure is the compatibility of the fuzzy envelope (see [Hesitant::fuzzy_envelope])
+    /// with a label of `domain` (see [Unified::max_min_in_domain]).
+    ///
+    /// # Arguments
+    /// * `domain`: Domain in which perform the unification.
+    /// * `alpha`: OWA weights parameter of the fuzzy envelope, in range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Hesitant;
+    /// # use assessment::utilities;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&domain, "between b and c").unwrap();
+    /// assert_eq!(
+    ///     *valuation.max_min_in_domain(&domain, 0.5).unwrap().measures(),
+    ///     vec![0.5, 1.0, 1.0, 0.5, 0.0]
+    /// );
+    /// let unified = valuation.max_min_in_domain(&unification_domain, 0.5).unwrap();
+    /// assert_eq!(
+    ///     unified.measures().iter().map(|m| utilities::math::round_f32(*m, 2)).collect::<Vec<_>>(),
+    ///     vec![0.67, 1.0, 0.33]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::InvalidOwaParameter**: If `alpha` isn't in range `[0, 1]`.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, UnifiedError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&domain, "at most b").unwrap();
+    /// assert_eq!(
+    ///     valuation.max_min_in_domain(&domain, 1.5),
+    ///     Err(UnifiedError::InvalidOwaParameter { alpha: 1.5 })
+    /// );
+    /// ```
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, UnifiedError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&domain, "at most b").unwrap();
+    /// assert_eq!(
+    ///     valuation.max_min_in_domain(&unification_domain, 0.5),
+    ///     Err(UnifiedError::NonBLTSDomain { domain: &unification_domain })
+    /// );
+    /// ```
+    pub fn max_min_in_domain<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+        alpha: f32,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        let envelope = self
+            .fuzzy_envelope(alpha)
+            .map_err(|_| UnifiedError::InvalidOwaParameter { alpha })?;
+        Unified::max_min_in_domain(domain, &envelope)
+    }
+
+    /// Transform into a Hesitant valuation in a different domain.
+    ///
+    /// Both bounds are placed at their symbolic positions in `domain`. When a bound doesn't land
+    /// exactly on a label, `policy` decides which label is used (halfway bounds are rounded up
+    /// with [HesitantTransformPolicy::Nearest]). The result is given by the
+    /// closest relation covering the new labels.
+    ///
+    /// # Arguments
+    /// * `domain`: Domain to be used.
+    /// * `policy`: Policy for bounds between two labels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, HesitantTransformPolicy};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let transform_domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e", "f", "g"].unwrap();
+    ///
+    /// for (text, policy, expected) in [
+    ///     ("c", HesitantTransformPolicy::Conservative, "d"),
+    ///     ("b", HesitantTransformPolicy::Conservative, "between b and c"),
+    ///     ("b", HesitantTransformPolicy::Nearest, "c"),
+    ///     ("between b and d", HesitantTransformPolicy::Conservative, "between b and f"),
+    ///     ("between b and d", HesitantTransformPolicy::Nearest, "between c and f"),
+    ///     ("at least d", HesitantTransformPolicy::Conservative, "at least e"),
+    ///     ("lower than b", HesitantTransformPolicy::Nearest, "a"),
+    /// ] {
+    ///     let valuation = Hesitant::parse_in_domain(&domain, text).unwrap();
+    ///     assert_eq!(
+    ///         valuation.transform_in_domain(&transform_domain, policy).unwrap().to_string(),
+    ///         expected
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Hesitant, HesitantTransformPolicy, UnifiedError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let transform_domain = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    ///
+    /// let valuation = Hesitant::parse_in_domain(&domain, "at most b").unwrap();
+    /// assert_eq!(
+    ///     valuation.transform_in_domain(&transform_domain, HesitantTransformPolicy::Nearest),
+    ///     Err(UnifiedError::NonBLTSDomain { domain: &transform_domain })
+    /// );
+    /// ```
+    ///
+    pub fn transform_in_domain(
+        &self,
+        domain: &'domain Qualitative<Trapezoidal>,
+        policy: HesitantTransformPolicy,
+    ) -> Result<Self, UnifiedError<'domain>> {
+        if !domain.is_blts() {
+            return Err(UnifiedError::NonBLTSDomain { domain });
+        }
+
+        let source_granularity = self.domain().cardinality() - 1;
+        let target_granularity = domain.cardinality() - 1;
+        let (lower, upper) = self.indexes();
+        let (lower, upper) = (lower * target_granularity, upper * target_granularity);
+        let (lower, upper) = match policy {
+            HesitantTransformPolicy::Conservative => (
+                lower / source_granularity,
+                upper.div_ceil(source_granularity),
+            ),
+            HesitantTransformPolicy::Nearest => (
+                (2 * lower + source_granularity) / (2 * source_granularity),
+                (2 * upper + source_granularity) / (2 * source_granularity),
+            ),
+        };
+        Ok(Hesitant::covering_in_domain(domain, lower, upper))
+    }
+}
+
+/// Generates a Unified valuation from a &Hesitant valuation.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Hesitant, Unified};
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuation = Hesitant::parse_in_domain(&domain, "at most b").unwrap();
+/// let unified = Unified::try_from(&valuation).unwrap();
+/// assert_eq!(*unified.measures(), vec![1.0, 1.0, 0.0]);
+/// ```
+///
+/// # Errors
+///
+/// **UnifiedError::NonBLTSDomain**: If valuation domain is a Non-BLTS domain.
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Hesitant, Unified, UnifiedError};
+/// let domain = qualitative_symmetric_domain!["a", "b"].unwrap();
+///
+/// let valuation = Hesitant::parse_in_domain(&domain, "at least a").unwrap();
+/// assert_eq!(
+///     Unified::try_from(&valuation),
+///     Err(UnifiedError::NonBLTSDomain { domain: &domain })
+/// );
+/// ```
+///
+impl<'domain> TryFrom<&Hesitant<'domain, Trapezoidal>> for Unified<'domain> {
+    type Error = UnifiedError<'domain>;
+
+    fn try_from(value: &Hesitant<'domain, Trapezoidal>) -> Result<Self, Self::Error> {
+        let (lower, upper) = value.indexes();
+        let measures = (0..value.domain().cardinality())
+            .map(|i| if (lower..=upper).contains(&i) { 1. } else { 0. })
+            .collect::<Vec<f32>>();
+        Unified::new(value.domain(), measures)
+    }
+}
+
+/// Generates a Unified valuation from a Hesitant valuation.
+///
+/// Wrapper of Unified::try_from(&Hesitant).
+///
+impl<'domain> TryFrom<Hesitant<'domain, Trapezoidal>> for Unified<'domain> {
+    type Error = UnifiedError<'domain>;
+
+    fn try_from(value: Hesitant<'domain, Trapezoidal>) -> Result<Self, Self::Error> {
+        Unified::try_from(&value)
+    }
+}
@@ -63,10 +63,10 @@ where
     /// );
     /// ```
     ///
-    pub fn unification(
+    pub fn unification<'target>(
         &self,
-        domain: &'domain Qualitative<Trapezoidal>,
-    ) -> Result<Unified, UnifiedError> {
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
         let (min, max) = self.normalize().value();
         let min_f32 = min as f32;
         let max_f32 = max as f32;
@@ -105,7 +105,7 @@ where
     /// assert_eq!(unified.value(), (2, 2));
     /// ```
     ///
-    pub fn transform_in_domain(&self, domain: &'domain Quantitative<T>) -> Interval<T> {
+    pub fn transform_in_domain(&self, domain: &'domain Quantitative<T>) -> Interval<'domain, T> {
         let (old_min, old_max) = self.value();
         Interval::new(
             domain,
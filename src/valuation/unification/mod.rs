@@ -1,12 +1,11 @@
-pub use numeric::*;
-pub use single::*;
-pub use two_tuple::*;
-pub use unified::{Unified, UnifiedError};
+pub use unified::{SharedUnified, Unified, UnifiedError};
 
 pub mod single;
 
 pub mod two_tuple;
 
+pub mod hesitant;
+
 pub mod numeric;
 
 pub mod interval;
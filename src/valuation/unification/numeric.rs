@@ -63,10 +63,10 @@ where
     /// );
     /// ```
     ///
-    pub fn unification(
+    pub fn unification<'target>(
         &self,
-        domain: &'domain Qualitative<Trapezoidal>,
-    ) -> Result<Unified, UnifiedError> {
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
         let value = self.normalize().value() as f32;
         let measures = (0..domain.cardinality())
             .map(|i| {
@@ -103,7 +103,7 @@ where
     /// assert_eq!(unified.value(), 2);
     /// ```
     ///
-    pub fn transform_in_domain(&self, domain: &'domain Quantitative<T>) -> Numeric<T> {
+    pub fn transform_in_domain(&self, domain: &'domain Quantitative<T>) -> Numeric<'domain, T> {
         Numeric::new(
             domain,
             crate::utilities::math::transform_range(
@@ -4,7 +4,7 @@ use crate::fuzzy::LabelMembership;
 use crate::valuation::{Single, SingleError, TwoTuple, Unified, UnifiedError};
 use std::fmt::Display;
 
-impl<'domain> Single<'domain, Trapezoidal> {
+impl<'domain, T: LabelMembership> Single<'domain, T> {
     /// Unification of a Single valuation in a new domain.
     ///
     /// # Arguments
@@ -38,6 +38,19 @@ impl<'domain> Single<'domain, Trapezoidal> {
     /// }
     /// ```
     ///
+    /// A single label domain is mapped to the last label.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::Single;
+    /// let domain = qualitative_symmetric_domain!["a"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    ///
+    /// let valuation = Single::new_by_label_index(&domain, 0).unwrap();
+    /// let unified = valuation.unification_in_domain(&unification_domain).unwrap();
+    /// assert_eq!(*unified.measures(), vec![0.0, 0.0, 1.0]);
+    /// ```
+    ///
     /// # Errors
     ///
     /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
@@ -56,18 +69,23 @@ impl<'domain> Single<'domain, Trapezoidal> {
     /// );
     /// ```
     ///
-    pub fn unification_in_domain(
+    pub fn unification_in_domain<'target>(
         &self,
-        domain: &'domain Qualitative<Trapezoidal>,
-    ) -> Result<Unified, UnifiedError<'domain>> {
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
         let mut measures: Vec<f32> = vec![0.; domain.cardinality()];
-        measures[self.index() * (domain.cardinality() - 1) / (self.domain().cardinality() - 1)] =
-            1.;
+        measures[rescale_label(self.index(), self.domain(), domain)] = 1.;
         Unified::new(domain, measures)
     }
+}
 
+impl<'domain> Single<'domain, Trapezoidal> {
     /// Transform into a Single valuation in a different domain.
     ///
+    /// The label is mapped to the nearest label of `domain` to its symbolic position (the label of
+    /// [Single::transform_exact_in_domain]), so information is lost when domains granularities
+    /// aren't multiples.
+    ///
     /// # Arguments
     /// * `domain`: Domain to be used.
     ///
@@ -84,6 +102,11 @@ impl<'domain> Single<'domain, Trapezoidal> {
     /// let transformed = valuation.transform_in_domain(&unification_domain).unwrap();
     /// let expected = Single::new_by_label_index(&unification_domain, 2).unwrap();
     /// assert_eq!(transformed, expected);
+    ///
+    /// // Label "d" is placed at (c, -0.5) and rounded to "c"
+    /// let valuation = Single::new_by_label_index(&unification_domain, 3).unwrap();
+    /// let transformed = valuation.transform_in_domain(&domain).unwrap();
+    /// assert_eq!(transformed, Single::new_by_label_index(&domain, 2).unwrap());
     /// ```
     ///
     /// # Errors
@@ -108,18 +131,77 @@ impl<'domain> Single<'domain, Trapezoidal> {
         &self,
         domain: &'domain Qualitative<Trapezoidal>,
     ) -> Result<Self, UnifiedError<'domain>> {
-        if !domain.is_blts() {
-            Err(UnifiedError::NonBLTSDomain { domain })
-        } else {
-            Ok(Single::new_by_label_index(
-                domain,
-                self.index() * (domain.cardinality() - 1) / (self.domain().cardinality() - 1),
-            )
-            .unwrap())
-        }
+        let transformed = self.transform_exact_in_domain(domain)?;
+        Ok(Single::new_by_label_index(domain, transformed.index()).unwrap())
+    }
+
+    /// Transform into a TwoTuple valuation in a different domain.
+    ///
+    /// The label is placed at its symbolic position in `domain`, so the transformation is exact
+    /// even when domains granularities aren't multiples (see [TwoTuple::transform_in_domain]).
+    ///
+    /// # Arguments
+    /// * `domain`: Domain to be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Single, TwoTuple};
+    /// # use assessment::utilities;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// let valuation = Single::new_by_label_index(&domain, 1).unwrap();
+    /// let transformed = valuation.transform_exact_in_domain(&unification_domain).unwrap();
+    /// let expected = TwoTuple::new_by_label_index(&unification_domain, 2, 0.0).unwrap();
+    /// assert_eq!(transformed, expected);
+    ///
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    /// let valuation = Single::new_by_label_index(&domain, 1).unwrap();
+    /// let transformed = valuation.transform_exact_in_domain(&unification_domain).unwrap();
+    /// assert!(utilities::math::approx_equal_f32(transformed.inverse_delta(), 4.0 / 3.0, 5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::{Single, UnifiedError};
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c", "d"].unwrap();
+    ///
+    /// let valuation = Single::new_by_label_index(&domain, 1).unwrap();
+    /// assert_eq!(
+    ///     valuation.transform_exact_in_domain(&unification_domain),
+    ///     Err(UnifiedError::NonBLTSDomain { domain: &unification_domain })
+    /// );
+    /// ```
+    ///
+    pub fn transform_exact_in_domain(
+        &self,
+        domain: &'domain Qualitative<Trapezoidal>,
+    ) -> Result<TwoTuple<'domain, Trapezoidal>, UnifiedError<'domain>> {
+        TwoTuple::new_by_label_index(self.domain(), self.index(), 0.0)
+            .unwrap()
+            .transform_in_domain(domain)
     }
 }
 
+/// Label index of `target` with the same relative position as `index` in `source`.
+///
+/// The single label of a one label `source` is mapped to the last label of `target`.
+fn rescale_label<S: LabelMembership>(
+    index: usize,
+    source: &Qualitative<S>,
+    target: &Qualitative<Trapezoidal>,
+) -> usize {
+    let (from, to) = (source.cardinality() - 1, target.cardinality() - 1);
+    (index * to).checked_div(from).unwrap_or(to)
+}
+
 /// Generates a Unified valuation from a &Linguistic valuation.
 ///
 /// # Examples
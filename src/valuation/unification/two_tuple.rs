@@ -5,7 +5,7 @@ use crate::utilities;
 use crate::valuation::{Single, TwoTuple, TwoTupleError, Unified, UnifiedError};
 use std::fmt::Display;
 
-impl<'domain> TwoTuple<'domain, Trapezoidal> {
+impl<'domain, T: LabelMembership> TwoTuple<'domain, T> {
     /// Unification of a valuation in a new domain.
     ///
     /// # Arguments
@@ -57,12 +57,11 @@ impl<'domain> TwoTuple<'domain, Trapezoidal> {
     /// );
     /// ```
     ///
-    pub fn unification_in_domain(
+    pub fn unification_in_domain<'target>(
         &self,
-        domain: &'domain Qualitative<Trapezoidal>,
-    ) -> Result<Unified, UnifiedError<'domain>> {
-        let beta = (self.inverse_delta() * (domain.cardinality() - 1) as f32)
-            / (self.domain().cardinality() - 1) as f32;
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        let beta = rescale_beta(self.inverse_delta(), self.domain(), domain);
         let index = beta.round() as usize;
         let alpha = utilities::math::round_f32(beta - index as f32, 5);
 
@@ -73,7 +72,9 @@ impl<'domain> TwoTuple<'domain, Trapezoidal> {
         }
         Unified::new(domain, measures)
     }
+}
 
+impl<'domain> TwoTuple<'domain, Trapezoidal> {
     /// Transform into a TwoTuple valuation in a different domain.
     ///
     /// # Arguments
@@ -94,6 +95,19 @@ impl<'domain> TwoTuple<'domain, Trapezoidal> {
     /// assert_eq!(transformed, expected);
     /// ```
     ///
+    /// A single label domain is mapped to the last label.
+    ///
+    /// ```
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use assessment::valuation::TwoTuple;
+    /// let domain = qualitative_symmetric_domain!["a"].unwrap();
+    /// let unification_domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    ///
+    /// let valuation = TwoTuple::new_by_label_index(&domain, 0, 0.0).unwrap();
+    /// let transformed = valuation.transform_in_domain(&unification_domain).unwrap();
+    /// assert_eq!(transformed, TwoTuple::new_by_label_index(&unification_domain, 2, 0.0).unwrap());
+    /// ```
+    ///
     /// # Errors
     ///
     /// **UnifiedError::NonBLTSDomain**: If `domain` is a Non-BLTS domain.
@@ -121,14 +135,29 @@ impl<'domain> TwoTuple<'domain, Trapezoidal> {
         } else {
             Ok(TwoTuple::delta(
                 domain,
-                (self.inverse_delta() * (domain.cardinality() - 1) as f32)
-                    / (self.domain().cardinality() - 1) as f32,
+                rescale_beta(self.inverse_delta(), self.domain(), domain),
             )
             .unwrap())
         }
     }
 }
 
+/// Symbolic value in `target` with the same relative position as `beta` in `source`.
+///
+/// The single label of a one label `source` is mapped to the last label of `target`.
+fn rescale_beta<S: LabelMembership>(
+    beta: f32,
+    source: &Qualitative<S>,
+    target: &Qualitative<Trapezoidal>,
+) -> f32 {
+    let (from, to) = (source.cardinality() - 1, target.cardinality() - 1);
+    if from == 0 {
+        to as f32
+    } else {
+        beta * to as f32 / from as f32
+    }
+}
+
 /// Generates a Unified valuation from a &TwoTuple valuation.
 ///
 /// # Examples
@@ -1,19 +1,30 @@
-use crate::domain::Qualitative;
+use crate::domain::{DomainHandle, Qualitative};
 use crate::fuzzy::membership::Trapezoidal;
-use crate::valuation::Linguistic;
+use crate::valuation::{Interval, Linguistic, ValuationKind};
 use crate::Valuation;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Unified linguistic valuations.
 ///
 /// Unified valuations are a special type of linguistic valuations used for conversion
 /// between different valuations.
+///
+/// The domain is borrowed by default. See [SharedUnified] for valuations owning their domain.
 #[derive(Debug, PartialEq)]
-pub struct Unified<'domain> {
-    domain: &'domain Qualitative<Trapezoidal>,
+pub struct Unified<
+    'domain,
+    H: DomainHandle<Qualitative<Trapezoidal>> = &'domain Qualitative<Trapezoidal>,
+> {
+    domain: H,
     measures: Vec<f32>,
+    lifetime: PhantomData<&'domain ()>,
 }
 
+/// Unified valuation sharing the ownership of its domain.
+pub type SharedUnified = Unified<'static, Arc<Qualitative<Trapezoidal>>>;
+
 /// Unified errors types.
 #[derive(Debug, PartialEq)]
 pub enum UnifiedError<'domain> {
@@ -28,6 +39,8 @@ pub enum UnifiedError<'domain> {
     },
     /// Invalid measure value.
     InvalidMeasureValue { measure: f32 },
+    /// Invalid OWA weights parameter of a fuzzy envelope.
+    InvalidOwaParameter { alpha: f32 },
 }
 
 // Note: + Display added because clion doesn't detect here correctly the trait_alias feature
@@ -53,12 +66,80 @@ impl<'domain> Display for UnifiedError<'domain> {
                     measure
                 )
             }
+            InvalidOwaParameter { alpha } => {
+                write!(
+                    f,
+                    "Invalid OWA weights parameter {} (should be in range [0, 1]).",
+                    alpha
+                )
+            }
         }
     }
 }
 
-impl<'domain> Linguistic for Unified<'domain> {}
-impl<'domain> Valuation for Unified<'domain> {}
+impl<'domain, H: DomainHandle<Qualitative<Trapezoidal>>> Display for Unified<'domain, H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let measures = self
+            .domain
+            .get_labels_names()
+            .iter()
+            .zip(&self.measures)
+            .map(|(name, measure)| format!("{} => {:.2}", name, measure))
+            .collect::<Vec<String>>();
+        write!(f, "[{}]", measures.join(", "))
+    }
+}
+
+impl<'domain, H: DomainHandle<Qualitative<Trapezoidal>>> Linguistic for Unified<'domain, H> {
+    fn cardinality(&self) -> usize {
+        self.domain.cardinality()
+    }
+
+    fn symbolic_range(&self) -> (f32, f32) {
+        let chi = self.chi();
+        (chi, chi)
+    }
+}
+
+/// Unified valuations as generic valuations.
+///
+/// # Examples
+///
+/// ```
+/// # use assessment::qualitative_symmetric_domain;
+/// # use assessment::valuation::{Unified, ValuationKind};
+/// # use assessment::Valuation;
+/// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+/// let valuation: Box<dyn Valuation> = Box::new(Unified::new(&domain, vec![0.0, 1.0, 1.0]).unwrap());
+///
+/// assert_eq!(valuation.kind(), ValuationKind::Unified);
+/// assert_eq!(format!("{}", valuation), "[a => 0.00, b => 1.00, c => 1.00]");
+/// assert_eq!(valuation.normalization().value(), (0.75, 0.75));
+/// assert_eq!(format!("{}", valuation.negation()), "[a => 1.00, b => 1.00, c => 0.00]");
+///
+/// let blts = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+/// assert_eq!(*valuation.unification(&blts).unwrap().measures(), vec![0.0, 0.0, 1.0, 0.0, 1.0]);
+/// ```
+impl<'domain, H: DomainHandle<Qualitative<Trapezoidal>>> Valuation for Unified<'domain, H> {
+    fn kind(&self) -> ValuationKind {
+        ValuationKind::Unified
+    }
+
+    fn normalization(&self) -> Interval<'static, f64> {
+        crate::valuation::linguistic::normalize_symbolic_range(self)
+    }
+
+    fn negation(&self) -> Box<dyn Valuation + '_> {
+        Box::new(self.neg())
+    }
+
+    fn unification<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        self.as_borrowed().unification_in_domain(domain)
+    }
+}
 
 impl<'domain> Unified<'domain> {
     /// Creates a new valuation given `measures` in `domain`.
@@ -135,23 +216,71 @@ impl<'domain> Unified<'domain> {
                     return Err(InvalidMeasureValue { measure: *measure });
                 }
             }
-            Ok(Self { domain, measures })
+            Ok(Self {
+                domain,
+                measures,
+                lifetime: PhantomData,
+            })
         }
     }
 
-    /// Returns valuation measures.
+    /// Creates a new valuation with the compatibility (maxmin) of `membership` with each label
+    /// of `domain`.
+    ///
+    /// `membership` should be defined over the same universe than `domain` labels.
+    ///
+    /// To unify a Hesitant valuation against its fuzzy envelope, see
+    /// [Hesitant::max_min_in_domain](crate::valuation::Hesitant::max_min_in_domain).
+    ///
+    /// # Arguments
+    /// * `domain`: A qualitative domain reference.
+    /// * `membership`: Membership function, e.g., the envelope of a hesitant valuation.
     ///
     /// # Examples
     ///
     /// ```
     /// # use assessment::valuation::Unified;
+    /// # use assessment::fuzzy::membership::Trapezoidal;
     /// # use assessment::qualitative_symmetric_domain;
-    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    /// let membership = Trapezoidal::new(vec![0.25, 0.5, 0.75]).unwrap();
     ///
-    /// assert_eq!(*Unified::new(&domain, vec![0.0, 0.5, 0.0]).unwrap().measures(), vec![0.0, 0.5, 0.0]);
+    /// assert_eq!(
+    ///     *Unified::max_min_in_domain(&domain, &membership).unwrap().measures(),
+    ///     vec![0.0, 0.5, 1.0, 0.5, 0.0]
+    /// );
     /// ```
-    pub fn measures(&self) -> &Vec<f32> {
-        &self.measures
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Unified, UnifiedError};
+    /// # use assessment::fuzzy::membership::Trapezoidal;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b"].unwrap();
+    /// let membership = Trapezoidal::new(vec![0.25, 0.5, 0.625]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Unified::max_min_in_domain(&domain, &membership),
+    ///     Err(UnifiedError::NonBLTSDomain { domain: &domain })
+    /// );
+    /// ```
+    pub fn max_min_in_domain(
+        domain: &'domain Qualitative<Trapezoidal>,
+        membership: &Trapezoidal,
+    ) -> Result<Self, UnifiedError<'domain>> {
+        let measures = (0..domain.cardinality())
+            .map(|i| {
+                domain
+                    .get_label_by_index(i)
+                    .unwrap()
+                    .membership()
+                    .max_min_membership(membership)
+            })
+            .collect::<Vec<f32>>();
+        Unified::new(domain, measures)
     }
 
     /// Returns valuation domain.
@@ -169,6 +298,145 @@ impl<'domain> Unified<'domain> {
         self.domain
     }
 
+    /// Expresses the valuation in another BLTS `domain`.
+    ///
+    /// Each source label is placed at its symbolic position in `domain` and its measure is
+    /// split linearly between the two closest target labels. When several source labels
+    /// reach the same target label, the highest measure is kept.
+    ///
+    /// # Arguments
+    /// * `domain`: Target BLTS domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Unified;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let source = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let target = qualitative_symmetric_domain!["a", "b", "c", "d", "e"].unwrap();
+    ///
+    /// let valuation = Unified::new(&source, vec![0.0, 0.6, 0.4]).unwrap();
+    /// assert_eq!(
+    ///     *valuation.unification_in_domain(&target).unwrap().measures(),
+    ///     vec![0.0, 0.0, 0.6, 0.0, 0.4]
+    /// );
+    ///
+    /// let valuation = Unified::new(&target, vec![0.0, 0.8, 0.2, 0.0, 0.0]).unwrap();
+    /// assert_eq!(
+    ///     *valuation.unification_in_domain(&source).unwrap().measures(),
+    ///     vec![0.4, 0.4, 0.0]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// **UnifiedError::NonBLTSDomain**: If `domain` is a non-BLTS domain.
+    ///
+    /// ```
+    /// # use assessment::valuation::{Unified, UnifiedError};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let source = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    /// let target = qualitative_symmetric_domain!["a", "b"].unwrap();
+    ///
+    /// assert_eq!(
+    ///     Unified::new(&source, vec![0.0, 1.0, 0.0]).unwrap().unification_in_domain(&target),
+    ///     Err(UnifiedError::NonBLTSDomain { domain: &target })
+    /// );
+    /// ```
+    pub fn unification_in_domain<'target>(
+        &self,
+        domain: &'target Qualitative<Trapezoidal>,
+    ) -> Result<Unified<'target>, UnifiedError<'target>> {
+        if !domain.is_blts() {
+            return Err(UnifiedError::NonBLTSDomain { domain });
+        }
+
+        let source_granularity = (self.domain.cardinality() - 1) as f32;
+        let target_granularity = (domain.cardinality() - 1) as f32;
+        let mut measures = vec![0.0f32; domain.cardinality()];
+        for (index, measure) in self.measures.iter().enumerate() {
+            let beta = index as f32 * target_granularity / source_granularity;
+            let lower = beta.floor() as usize;
+            let upper = beta.ceil() as usize;
+            let weight = beta - lower as f32;
+            measures[lower] = measures[lower].max(measure * (1. - weight));
+            measures[upper] = measures[upper].max(measure * weight);
+        }
+
+        Unified::new(domain, measures)
+    }
+}
+
+impl SharedUnified {
+    /// Creates a new valuation given `measures` in a shared `domain`.
+    ///
+    /// # Arguments
+    /// * `domain`: A reference-counted qualitative domain.
+    /// * `measures`: Unified valuation measures in `domain`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{SharedUnified, Unified};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let valuation: SharedUnified = {
+    ///     let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    ///     Unified::new_shared(&domain, vec![0.0, 0.5, 1.0]).unwrap()
+    /// };
+    ///
+    /// let handle = std::thread::spawn(move || valuation.neg().to_string());
+    /// assert_eq!(handle.join().unwrap(), "[a => 1.00, b => 0.50, c => 0.00]");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [Unified::new].
+    pub fn new_shared(
+        domain: &Arc<Qualitative<Trapezoidal>>,
+        measures: Vec<f32>,
+    ) -> Result<Self, UnifiedError<'_>> {
+        let valuation = Unified::new(domain, measures)?;
+        Ok(Self {
+            domain: Arc::clone(domain),
+            measures: valuation.measures,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Returns valuation domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Unified;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let valuation = Unified::new_shared(&domain, vec![0.0, 0.5, 0.0]).unwrap();
+    /// assert_eq!(valuation.domain(), domain.as_ref());
+    /// ```
+    pub fn domain(&self) -> &Qualitative<Trapezoidal> {
+        &self.domain
+    }
+}
+
+impl<'domain, H: DomainHandle<Qualitative<Trapezoidal>>> Unified<'domain, H> {
+    /// Returns valuation measures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::Unified;
+    /// # use assessment::qualitative_symmetric_domain;
+    /// let domain = qualitative_symmetric_domain!["a", "b", "c"].unwrap();
+    ///
+    /// assert_eq!(*Unified::new(&domain, vec![0.0, 0.5, 0.0]).unwrap().measures(), vec![0.0, 0.5, 0.0]);
+    /// ```
+    pub fn measures(&self) -> &Vec<f32> {
+        &self.measures
+    }
+
     /// Value that resumes the valuation.
     ///
     /// # Examples
@@ -224,8 +492,32 @@ impl<'domain> Unified<'domain> {
     /// ```
     pub fn neg(&self) -> Self {
         Self {
-            domain: self.domain,
+            domain: self.domain.clone(),
             measures: self.measures.iter().copied().rev().collect(),
+            lifetime: PhantomData,
+        }
+    }
+
+    /// Borrowed view of the valuation.
+    ///
+    /// Gives access to the API only available for borrowed domains (unification, conversions...).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use assessment::valuation::{TwoTuple, Unified};
+    /// # use assessment::qualitative_symmetric_domain;
+    /// # use std::sync::Arc;
+    /// let domain = Arc::new(qualitative_symmetric_domain!["a", "b", "c"].unwrap());
+    /// let valuation = Unified::new_shared(&domain, vec![0.0, 1.0, 1.0]).unwrap();
+    /// assert_eq!(valuation.as_borrowed(), Unified::new(&domain, vec![0.0, 1.0, 1.0]).unwrap());
+    /// assert_eq!(TwoTuple::try_from(&valuation.as_borrowed()).unwrap().to_string(), "(c, -0.50)");
+    /// ```
+    pub fn as_borrowed(&self) -> Unified<'_> {
+        Unified {
+            domain: &self.domain,
+            measures: self.measures.clone(),
+            lifetime: PhantomData,
         }
     }
 }